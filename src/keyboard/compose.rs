@@ -0,0 +1,30 @@
+// Dead keys as their spacing accent, followed by the letters they combine with
+// and the resulting characters at the same positions
+const COMPOSE: [(char, &str, &str); 13] = [
+    ('`', "aeiouAEIOU", "àèìòùÀÈÌÒÙ"),
+    ('´', "aceinosuyzACEINOSUYZ", "áćéíńóśúýźÁĆÉÍŃÓŚÚÝŹ"),
+    ('^', "aeiouAEIOU", "âêîôûÂÊÎÔÛ"),
+    ('~', "anoANO", "ãñõÃÑÕ"),
+    ('¨', "aeiouyAEIOUY", "äëïöüÿÄËÏÖÜŸ"),
+    ('°', "auAU", "åůÅŮ"),
+    ('¸', "cgkstCGKST", "çģķşţÇĢĶŞŢ"),
+    ('ˇ', "cdenrstzCDENRSTZ", "čďěňřšťžČĎĚŇŘŠŤŽ"),
+    ('˘', "agAG", "ăğĂĞ"),
+    ('¯', "aeiouAEIOU", "āēīōūĀĒĪŌŪ"),
+    ('˛', "aeiuAEIU", "ąęįųĄĘĮŲ"),
+    ('˝', "ouOU", "őűŐŰ"),
+    ('˙', "ezEZ", "ėżĖŻ"),
+];
+
+pub fn compose(accent: char, letter: char) -> Option<char> {
+    let (_, letters, composed) = COMPOSE.iter().find(|(dead, _, _)| *dead == accent)?;
+    let idx = letters.chars().position(|ch| ch == letter)?;
+    composed.chars().nth(idx)
+}
+
+pub fn decompose(ch: char) -> Option<(char, char)> {
+    COMPOSE.iter().find_map(|(accent, letters, composed)| {
+        let idx = composed.chars().position(|composed| composed == ch)?;
+        Some((*accent, letters.chars().nth(idx)?))
+    })
+}
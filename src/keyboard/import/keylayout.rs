@@ -0,0 +1,384 @@
+use std::{collections::HashMap, fs, path::Path};
+
+use quick_xml::{
+    events::{BytesStart, Event},
+    Reader,
+};
+
+use super::{KeyMap, Symbol};
+
+// macOS virtual key codes. The key left of Z reports 50 on ISO keyboards,
+// the one left of 1 reports 10. ANSI keyboards have no code 10 and report 50
+// for the key left of 1 instead.
+const KEY_CODES: [(u16, &str); 48] = [
+    (10, "TLDE"),
+    (18, "AE01"),
+    (19, "AE02"),
+    (20, "AE03"),
+    (21, "AE04"),
+    (23, "AE05"),
+    (22, "AE06"),
+    (26, "AE07"),
+    (28, "AE08"),
+    (25, "AE09"),
+    (29, "AE10"),
+    (27, "AE11"),
+    (24, "AE12"),
+    (12, "AD01"),
+    (13, "AD02"),
+    (14, "AD03"),
+    (15, "AD04"),
+    (17, "AD05"),
+    (16, "AD06"),
+    (32, "AD07"),
+    (34, "AD08"),
+    (31, "AD09"),
+    (35, "AD10"),
+    (33, "AD11"),
+    (30, "AD12"),
+    (0, "AC01"),
+    (1, "AC02"),
+    (2, "AC03"),
+    (3, "AC04"),
+    (5, "AC05"),
+    (4, "AC06"),
+    (38, "AC07"),
+    (40, "AC08"),
+    (37, "AC09"),
+    (41, "AC10"),
+    (39, "AC11"),
+    (42, "BKSL"),
+    (50, "LSGT"),
+    (6, "AB01"),
+    (7, "AB02"),
+    (8, "AB03"),
+    (9, "AB04"),
+    (11, "AB05"),
+    (45, "AB06"),
+    (46, "AB07"),
+    (43, "AB08"),
+    (47, "AB09"),
+    (44, "AB10"),
+];
+
+// Whether shift and option are held
+type Modifiers = (bool, bool);
+
+// The modifiers selecting each level, as written in `<modifier keys="...">`
+const LEVELS: [Modifiers; 4] = [(false, false), (true, false), (false, true), (true, true)];
+
+enum Output {
+    Text(String),
+    Action(String),
+}
+
+#[derive(Default)]
+struct KeyLayoutMap {
+    base: Option<(String, usize)>,
+    keys: HashMap<u16, Output>,
+}
+
+#[derive(Default)]
+struct Document {
+    // The map set and modifier map of the first `<layout>`
+    layout: Option<(String, String)>,
+    // Modifier map id to the map index and required (shift, option) modifiers
+    selects: HashMap<String, Vec<(usize, Option<Modifiers>)>>,
+    default_index: HashMap<String, usize>,
+    key_maps: HashMap<(String, usize), KeyLayoutMap>,
+    // Action id to the output without any dead key state
+    actions: HashMap<String, Option<String>>,
+    // Action id to the dead key state it enters
+    dead_keys: HashMap<String, String>,
+    // Dead key state to the output when it is followed by an unrelated key
+    terminators: HashMap<String, String>,
+}
+
+// Loads a layout created with Ukelele or another `.keylayout` editor
+pub fn load(path: &Path) -> Result<KeyMap, String> {
+    let contents = fs::read_to_string(path).map_err(|err| err.to_string())?;
+    let document = parse(&contents)?;
+
+    let (map_set, modifiers) = document
+        .layout
+        .as_ref()
+        .ok_or_else(|| String::from("missing <layout> element"))?;
+    let selects = document
+        .selects
+        .get(modifiers)
+        .ok_or_else(|| format!("missing modifier map \"{}\"", modifiers))?;
+
+    let indices = LEVELS.map(|level| {
+        selects
+            .iter()
+            .find(|(_, mods)| *mods == Some(level))
+            .map(|(idx, _)| *idx)
+    });
+    let base_index = indices[0]
+        .or_else(|| document.default_index.get(modifiers).copied())
+        .unwrap_or(0);
+
+    let mut keys = KeyMap::default();
+    for (code, key) in KEY_CODES {
+        let mut levels = vec![None; 4];
+        for (level, index) in indices.iter().enumerate() {
+            let index = if level == 0 { Some(base_index) } else { *index };
+            if let Some(index) = index {
+                levels[level] = document.output(map_set, index, code);
+            }
+        }
+        if levels.iter().any(Option::is_some) {
+            keys.insert(key, levels);
+        }
+    }
+    keys.alias_ansi("TLDE", "LSGT");
+
+    Ok(keys)
+}
+
+impl Document {
+    fn output(&self, map_set: &str, index: usize, code: u16) -> Option<Symbol> {
+        let mut key_map = self.key_maps.get(&(map_set.to_string(), index))?;
+
+        // Key maps may inherit keys from another map
+        for _ in 0..8 {
+            if let Some(output) = key_map.keys.get(&code) {
+                return match output {
+                    Output::Text(text) => single_char(text).map(Symbol::Char),
+                    Output::Action(id) => match self.actions.get(id)? {
+                        Some(text) => single_char(text).map(Symbol::Char),
+                        None => {
+                            let state = self.dead_keys.get(id)?;
+                            single_char(self.terminators.get(state)?).map(Symbol::Dead)
+                        }
+                    },
+                };
+            }
+            key_map = self.key_maps.get(key_map.base.as_ref()?)?;
+        }
+
+        None
+    }
+}
+
+fn single_char(text: &str) -> Option<char> {
+    let mut chars = text.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) if !ch.is_control() => Some(ch),
+        _ => None,
+    }
+}
+
+fn parse(contents: &str) -> Result<Document, String> {
+    let mut reader = Reader::from_str(contents);
+    let mut document = Document::default();
+
+    let mut map_set = String::new();
+    let mut key_map = None;
+    let mut modifier_map = String::new();
+    let mut select = None;
+    let mut action = None;
+    let mut in_terminators = false;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|err| format!("at byte {}: {}", reader.error_position(), err))?;
+
+        let (element, is_empty) = match &event {
+            Event::Start(element) => (element, false),
+            Event::Empty(element) => (element, true),
+            Event::End(element) => {
+                match element.name().as_ref() {
+                    b"keyMap" => key_map = None,
+                    b"action" => action = None,
+                    b"terminators" => in_terminators = false,
+                    _ => {}
+                }
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+        let attrs = attributes(element)?;
+        let attr = |name: &str| attrs.get(name).cloned();
+        let index = |name: &str| {
+            attrs
+                .get(name)
+                .and_then(|value| value.parse::<usize>().ok())
+        };
+
+        match element.name().as_ref() {
+            b"layout" if document.layout.is_none() => {
+                if let (Some(map_set), Some(modifiers)) = (attr("mapSet"), attr("modifiers")) {
+                    document.layout = Some((map_set, modifiers));
+                }
+            }
+            b"modifierMap" => {
+                modifier_map = attr("id").unwrap_or_default();
+                if let Some(idx) = index("defaultIndex") {
+                    document.default_index.insert(modifier_map.clone(), idx);
+                }
+            }
+            b"keyMapSelect" => select = index("mapIndex"),
+            b"modifier" => {
+                if let Some(select) = select {
+                    let mods = parse_modifiers(&attr("keys").unwrap_or_default());
+                    document
+                        .selects
+                        .entry(modifier_map.clone())
+                        .or_default()
+                        .push((select, mods));
+                }
+            }
+            b"keyMapSet" => map_set = attr("id").unwrap_or_default(),
+            b"keyMap" => {
+                let Some(idx) = index("index") else {
+                    continue;
+                };
+                let base = match (attr("baseMapSet"), index("baseIndex")) {
+                    (Some(set), Some(idx)) => Some((set, idx)),
+                    (None, Some(idx)) => Some((map_set.clone(), idx)),
+                    _ => None,
+                };
+                document.key_maps.insert(
+                    (map_set.clone(), idx),
+                    KeyLayoutMap {
+                        base,
+                        keys: HashMap::new(),
+                    },
+                );
+                if !is_empty {
+                    key_map = Some((map_set.clone(), idx));
+                }
+            }
+            b"key" => {
+                let (Some(current), Some(code)) = (&key_map, attr("code")) else {
+                    continue;
+                };
+                let Ok(code) = code.parse::<u16>() else {
+                    continue;
+                };
+                let output = match (attr("output"), attr("action")) {
+                    (Some(text), _) => Output::Text(text),
+                    (None, Some(id)) => Output::Action(id),
+                    (None, None) => continue,
+                };
+                if let Some(map) = document.key_maps.get_mut(current) {
+                    map.keys.insert(code, output);
+                }
+            }
+            b"action" => action = attr("id"),
+            b"terminators" => in_terminators = !is_empty,
+            // Dead key states output their terminator when followed by an unrelated key
+            b"when" if in_terminators => {
+                if let (Some(state), Some(output)) = (attr("state"), attr("output")) {
+                    document.terminators.insert(state, output);
+                }
+            }
+            b"when" => {
+                if let (Some(id), Some("none")) = (&action, attr("state").as_deref()) {
+                    // Actions entering a dead key state have no output of their own
+                    document.actions.insert(id.clone(), attr("output"));
+                    if let Some(next) = attr("next") {
+                        document.dead_keys.insert(id.clone(), next);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Ok(document)
+}
+
+fn attributes(element: &BytesStart) -> Result<HashMap<String, String>, String> {
+    let mut attrs = HashMap::new();
+    for attr in element.attributes() {
+        let attr = attr.map_err(|err| err.to_string())?;
+        let key = String::from_utf8_lossy(attr.key.as_ref()).into_owned();
+        let value = attr.unescape_value().map_err(|err| err.to_string())?;
+        attrs.insert(key, value.into_owned());
+    }
+    Ok(attrs)
+}
+
+// Returns whether shift and option are required, or `None` if other
+// modifiers like caps lock or command are required as well
+fn parse_modifiers(keys: &str) -> Option<Modifiers> {
+    let mut shift = false;
+    let mut option = false;
+
+    // Modifiers with a trailing `?` are optional
+    for key in keys.split_whitespace().filter(|key| !key.ends_with('?')) {
+        match key {
+            "shift" | "rightShift" | "anyShift" => shift = true,
+            "option" | "rightOption" | "anyOption" => option = true,
+            _ => return None,
+        }
+    }
+
+    Some((shift, option))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::keyboard::Key;
+
+    use super::*;
+
+    fn fixture() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/keylayout/trainer.keylayout")
+    }
+
+    #[test]
+    fn imports_rows() {
+        let keys = load(&fixture()).unwrap();
+
+        // Key code 50 is left of Z on ISO keyboards
+        let iso = keys.iso("Trainer");
+        assert!(matches!(iso.row0[0], Key::Char('q', Some('Q'), None, _)));
+        assert!(matches!(
+            iso.row1[0],
+            Key::Char('a', Some('A'), Some('ä'), _)
+        ));
+        assert!(matches!(iso.row1[11], Key::Char('\\', Some('|'), None, _)));
+        assert!(matches!(iso.row2[0], Key::Char('<', Some('>'), _, _)));
+        assert!(matches!(iso.row2[1], Key::Char('z', Some('Z'), None, _)));
+
+        // Code 42 is the backslash above enter on ANSI keyboards
+        let ansi = keys.ansi("Trainer");
+        assert!(matches!(ansi.row0[12], Key::Char('\\', Some('|'), None, _)));
+        assert!(matches!(ansi.row2[0], Key::Char('z', Some('Z'), None, _)));
+    }
+
+    #[test]
+    fn places_key_left_of_one() {
+        let keys = load(&fixture()).unwrap();
+
+        let iso = keys.iso("Trainer");
+        assert!(matches!(iso.numbers[0], Key::Char('§', Some('°'), None, _)));
+
+        // ANSI keyboards report 50 for the key left of 1
+        let ansi = keys.ansi("Trainer");
+        assert!(matches!(ansi.numbers[0], Key::Char('<', Some('>'), _, _)));
+    }
+
+    #[test]
+    fn imports_dead_keys() {
+        // Dead keys take the output of their terminator
+        let iso = load(&fixture()).unwrap().iso("Trainer");
+        assert!(matches!(iso.row1[9], Key::Char('´', Some('`'), None, _)));
+        assert_eq!(iso.dead_keys, vec!['`', '´']);
+    }
+
+    #[test]
+    fn parses_modifiers() {
+        assert_eq!(parse_modifiers(""), Some((false, false)));
+        assert_eq!(parse_modifiers("anyShift caps?"), Some((true, false)));
+        assert_eq!(parse_modifiers("shift rightOption"), Some((true, true)));
+        assert_eq!(parse_modifiers("command"), None);
+    }
+}
@@ -0,0 +1,281 @@
+// Names of the printable keysyms in the Latin-1 range, starting at U+0020
+const ASCII: [&str; 95] = [
+    "space",
+    "exclam",
+    "quotedbl",
+    "numbersign",
+    "dollar",
+    "percent",
+    "ampersand",
+    "apostrophe",
+    "parenleft",
+    "parenright",
+    "asterisk",
+    "plus",
+    "comma",
+    "minus",
+    "period",
+    "slash",
+    "0",
+    "1",
+    "2",
+    "3",
+    "4",
+    "5",
+    "6",
+    "7",
+    "8",
+    "9",
+    "colon",
+    "semicolon",
+    "less",
+    "equal",
+    "greater",
+    "question",
+    "at",
+    "A",
+    "B",
+    "C",
+    "D",
+    "E",
+    "F",
+    "G",
+    "H",
+    "I",
+    "J",
+    "K",
+    "L",
+    "M",
+    "N",
+    "O",
+    "P",
+    "Q",
+    "R",
+    "S",
+    "T",
+    "U",
+    "V",
+    "W",
+    "X",
+    "Y",
+    "Z",
+    "bracketleft",
+    "backslash",
+    "bracketright",
+    "asciicircum",
+    "underscore",
+    "grave",
+    "a",
+    "b",
+    "c",
+    "d",
+    "e",
+    "f",
+    "g",
+    "h",
+    "i",
+    "j",
+    "k",
+    "l",
+    "m",
+    "n",
+    "o",
+    "p",
+    "q",
+    "r",
+    "s",
+    "t",
+    "u",
+    "v",
+    "w",
+    "x",
+    "y",
+    "z",
+    "braceleft",
+    "bar",
+    "braceright",
+    "asciitilde",
+];
+
+// Names of the keysyms starting at U+00A0
+const LATIN1: [&str; 96] = [
+    "nobreakspace",
+    "exclamdown",
+    "cent",
+    "sterling",
+    "currency",
+    "yen",
+    "brokenbar",
+    "section",
+    "diaeresis",
+    "copyright",
+    "ordfeminine",
+    "guillemotleft",
+    "notsign",
+    "hyphen",
+    "registered",
+    "macron",
+    "degree",
+    "plusminus",
+    "twosuperior",
+    "threesuperior",
+    "acute",
+    "mu",
+    "paragraph",
+    "periodcentered",
+    "cedilla",
+    "onesuperior",
+    "masculine",
+    "guillemotright",
+    "onequarter",
+    "onehalf",
+    "threequarters",
+    "questiondown",
+    "Agrave",
+    "Aacute",
+    "Acircumflex",
+    "Atilde",
+    "Adiaeresis",
+    "Aring",
+    "AE",
+    "Ccedilla",
+    "Egrave",
+    "Eacute",
+    "Ecircumflex",
+    "Ediaeresis",
+    "Igrave",
+    "Iacute",
+    "Icircumflex",
+    "Idiaeresis",
+    "ETH",
+    "Ntilde",
+    "Ograve",
+    "Oacute",
+    "Ocircumflex",
+    "Otilde",
+    "Odiaeresis",
+    "multiply",
+    "Oslash",
+    "Ugrave",
+    "Uacute",
+    "Ucircumflex",
+    "Udiaeresis",
+    "Yacute",
+    "THORN",
+    "ssharp",
+    "agrave",
+    "aacute",
+    "acircumflex",
+    "atilde",
+    "adiaeresis",
+    "aring",
+    "ae",
+    "ccedilla",
+    "egrave",
+    "eacute",
+    "ecircumflex",
+    "ediaeresis",
+    "igrave",
+    "iacute",
+    "icircumflex",
+    "idiaeresis",
+    "eth",
+    "ntilde",
+    "ograve",
+    "oacute",
+    "ocircumflex",
+    "otilde",
+    "odiaeresis",
+    "division",
+    "oslash",
+    "ugrave",
+    "uacute",
+    "ucircumflex",
+    "udiaeresis",
+    "yacute",
+    "thorn",
+    "ydiaeresis",
+];
+
+// Alternative spellings and common keysyms outside of Latin-1
+const OTHER: [(&str, char); 30] = [
+    ("quoteright", '\''),
+    ("quoteleft", '`'),
+    ("guillemetleft", '«'),
+    ("guillemetright", '»'),
+    ("ordmasculine", 'º'),
+    ("Eth", 'Ð'),
+    ("Thorn", 'Þ'),
+    ("Ooblique", 'Ø'),
+    ("ooblique", 'ø'),
+    ("EuroSign", '€'),
+    ("oe", 'œ'),
+    ("OE", 'Œ'),
+    ("lstroke", 'ł'),
+    ("Lstroke", 'Ł'),
+    ("dstroke", 'đ'),
+    ("Dstroke", 'Đ'),
+    ("hstroke", 'ħ'),
+    ("Hstroke", 'Ħ'),
+    ("scaron", 'š'),
+    ("Scaron", 'Š'),
+    ("zcaron", 'ž'),
+    ("Zcaron", 'Ž'),
+    ("ccaron", 'č'),
+    ("Ccaron", 'Č'),
+    ("endash", '–'),
+    ("emdash", '—'),
+    ("leftsinglequotemark", '‘'),
+    ("rightsinglequotemark", '’'),
+    ("leftdoublequotemark", '“'),
+    ("rightdoublequotemark", '”'),
+];
+
+// Dead keys, stored as their spacing accent
+const DEAD: [(&str, char); 13] = [
+    ("dead_grave", '`'),
+    ("dead_acute", '´'),
+    ("dead_circumflex", '^'),
+    ("dead_tilde", '~'),
+    ("dead_diaeresis", '¨'),
+    ("dead_abovering", '°'),
+    ("dead_cedilla", '¸'),
+    ("dead_caron", 'ˇ'),
+    ("dead_breve", '˘'),
+    ("dead_macron", '¯'),
+    ("dead_ogonek", '˛'),
+    ("dead_doubleacute", '˝'),
+    ("dead_abovedot", '˙'),
+];
+
+pub fn dead_key(name: &str) -> Option<char> {
+    DEAD.iter().find(|(sym, _)| *sym == name).map(|(_, ch)| *ch)
+}
+
+pub fn to_char(name: &str) -> Option<char> {
+    // Unicode keysyms are written as U20AC or 0x10020ac
+    if let Some(hex) = name.strip_prefix('U').filter(|hex| hex.len() >= 4) {
+        if let Ok(code) = u32::from_str_radix(hex, 16) {
+            return char::from_u32(code);
+        }
+    }
+    if let Some(hex) = name.strip_prefix("0x") {
+        let code = u32::from_str_radix(hex, 16).ok()?;
+        return match code {
+            0x0100_0000.. => char::from_u32(code - 0x0100_0000),
+            0x20..=0xff => char::from_u32(code),
+            _ => None,
+        };
+    }
+
+    if let Some(idx) = ASCII.iter().position(|sym| *sym == name) {
+        return char::from_u32(0x20 + idx as u32);
+    }
+    if let Some(idx) = LATIN1.iter().position(|sym| *sym == name) {
+        return char::from_u32(0xa0 + idx as u32);
+    }
+    OTHER
+        .iter()
+        .find(|(sym, _)| *sym == name)
+        .map(|(_, ch)| *ch)
+}
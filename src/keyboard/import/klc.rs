@@ -0,0 +1,237 @@
+use std::{fs, path::Path};
+
+use super::{KeyMap, Symbol};
+
+// Scan codes of the keys the trainer knows about
+const SCAN_CODES: [(u8, &str); 48] = [
+    (0x29, "TLDE"),
+    (0x02, "AE01"),
+    (0x03, "AE02"),
+    (0x04, "AE03"),
+    (0x05, "AE04"),
+    (0x06, "AE05"),
+    (0x07, "AE06"),
+    (0x08, "AE07"),
+    (0x09, "AE08"),
+    (0x0a, "AE09"),
+    (0x0b, "AE10"),
+    (0x0c, "AE11"),
+    (0x0d, "AE12"),
+    (0x10, "AD01"),
+    (0x11, "AD02"),
+    (0x12, "AD03"),
+    (0x13, "AD04"),
+    (0x14, "AD05"),
+    (0x15, "AD06"),
+    (0x16, "AD07"),
+    (0x17, "AD08"),
+    (0x18, "AD09"),
+    (0x19, "AD10"),
+    (0x1a, "AD11"),
+    (0x1b, "AD12"),
+    (0x1e, "AC01"),
+    (0x1f, "AC02"),
+    (0x20, "AC03"),
+    (0x21, "AC04"),
+    (0x22, "AC05"),
+    (0x23, "AC06"),
+    (0x24, "AC07"),
+    (0x25, "AC08"),
+    (0x26, "AC09"),
+    (0x27, "AC10"),
+    (0x28, "AC11"),
+    (0x2b, "BKSL"),
+    (0x56, "LSGT"),
+    (0x2c, "AB01"),
+    (0x2d, "AB02"),
+    (0x2e, "AB03"),
+    (0x2f, "AB04"),
+    (0x30, "AB05"),
+    (0x31, "AB06"),
+    (0x32, "AB07"),
+    (0x33, "AB08"),
+    (0x34, "AB09"),
+    (0x35, "AB10"),
+];
+
+const SECTIONS: [&str; 17] = [
+    "KBD",
+    "COPYRIGHT",
+    "COMPANY",
+    "LOCALENAME",
+    "LOCALEID",
+    "VERSION",
+    "ATTRIBUTES",
+    "SHIFTSTATE",
+    "LAYOUT",
+    "DEADKEY",
+    "LIGATURE",
+    "KEYNAME",
+    "KEYNAME_EXT",
+    "KEYNAME_DEAD",
+    "DESCRIPTIONS",
+    "LANGUAGENAMES",
+    "ENDKBD",
+];
+
+// Loads a layout saved by the Microsoft Keyboard Layout Creator
+pub fn load(path: &Path) -> Result<KeyMap, String> {
+    let bytes = fs::read(path).map_err(|err| err.to_string())?;
+    parse(&decode(&bytes)?)
+}
+
+fn decode(bytes: &[u8]) -> Result<String, String> {
+    match bytes {
+        [0xff, 0xfe, rest @ ..] => {
+            let units = rest
+                .chunks_exact(2)
+                .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+                .collect::<Vec<_>>();
+            String::from_utf16(&units).map_err(|_| String::from("invalid UTF-16 text"))
+        }
+        [0xef, 0xbb, 0xbf, rest @ ..] => {
+            String::from_utf8(rest.to_vec()).map_err(|_| String::from("invalid UTF-8 text"))
+        }
+        _ => String::from_utf8(bytes.to_vec()).map_err(|_| String::from("invalid UTF-8 text")),
+    }
+}
+
+fn parse(contents: &str) -> Result<KeyMap, String> {
+    let mut keys = KeyMap::default();
+    let mut section = "";
+    let mut shift_states = Vec::new();
+
+    for (idx, line) in contents.lines().enumerate() {
+        let line = line.split("//").next().unwrap_or("").trim();
+        let fields = line.split_whitespace().collect::<Vec<_>>();
+        let Some(first) = fields.first() else {
+            continue;
+        };
+
+        if let Some(name) = SECTIONS.iter().find(|name| *name == first) {
+            section = name;
+            continue;
+        }
+
+        match section {
+            "SHIFTSTATE" => {
+                let state = first
+                    .parse::<u8>()
+                    .map_err(|_| format!("line {}: invalid shift state", idx + 1))?;
+                shift_states.push(state);
+            }
+            "LAYOUT" => {
+                let Ok(scan_code) = u8::from_str_radix(first, 16) else {
+                    return Err(format!("line {}: invalid scan code \"{}\"", idx + 1, first));
+                };
+                let Some((_, key)) = SCAN_CODES.iter().find(|(code, _)| *code == scan_code) else {
+                    continue;
+                };
+
+                // Columns: scan code, virtual key, caps lock behavior, one per shift state
+                let mut levels = vec![None; 4];
+                for (state, value) in shift_states.iter().zip(fields.iter().skip(3)) {
+                    let level = match state {
+                        0 => 0,
+                        1 => 1,
+                        6 => 2,
+                        7 => 3,
+                        _ => continue,
+                    };
+                    levels[level] = parse_char(value);
+                }
+                keys.insert(key, levels);
+            }
+            _ => {}
+        }
+    }
+
+    if shift_states.is_empty() {
+        return Err(String::from("missing SHIFTSTATE section"));
+    }
+    Ok(keys)
+}
+
+// Characters are either written literally or as four hex digits, `-1` means
+// no character and a trailing `@` marks a dead key
+fn parse_char(value: &str) -> Option<Symbol> {
+    if let Some(value) = value.strip_suffix('@') {
+        return parse_plain_char(value).map(Symbol::Dead);
+    }
+    parse_plain_char(value).map(Symbol::Char)
+}
+
+fn parse_plain_char(value: &str) -> Option<char> {
+    if value == "-1" || value == "%%" {
+        return None;
+    }
+
+    let mut chars = value.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Some(ch),
+        _ => char::from_u32(u32::from_str_radix(value, 16).ok()?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::keyboard::Key;
+
+    use super::*;
+
+    fn fixture() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/klc/trainer.klc")
+    }
+
+    #[test]
+    fn imports_rows() {
+        let keys = load(&fixture()).unwrap();
+
+        let iso = keys.iso("Trainer");
+        assert!(matches!(iso.row0[0], Key::Char('q', Some('Q'), None, _)));
+        assert!(matches!(iso.row0[2], Key::None));
+        assert!(matches!(
+            iso.row1[0],
+            Key::Char('a', Some('A'), Some('ä'), _)
+        ));
+        assert!(matches!(iso.row1[11], Key::Char('\\', Some('|'), None, _)));
+        assert!(matches!(iso.row2[0], Key::Char('<', Some('>'), _, _)));
+        assert!(matches!(iso.row2[1], Key::Char('z', Some('Z'), None, _)));
+
+        // ANSI keyboards have no key left of Z and keep backslash on the top row
+        let ansi = keys.ansi("Trainer");
+        assert!(matches!(ansi.row0[12], Key::Char('\\', Some('|'), None, _)));
+        assert!(matches!(ansi.row2[0], Key::Char('z', Some('Z'), None, _)));
+    }
+
+    #[test]
+    fn imports_dead_keys() {
+        let iso = load(&fixture()).unwrap().iso("Trainer");
+        assert!(matches!(iso.row1[9], Key::Char('´', Some('`'), None, _)));
+        assert_eq!(iso.dead_keys, vec!['`', '´']);
+    }
+
+    #[test]
+    fn parses_characters() {
+        assert_eq!(parse_char("q"), Some(Symbol::Char('q')));
+        assert_eq!(parse_char("00e4"), Some(Symbol::Char('ä')));
+        assert_eq!(parse_char("00b4@"), Some(Symbol::Dead('´')));
+        assert_eq!(parse_char("-1"), None);
+    }
+
+    #[test]
+    fn decodes_utf16() {
+        let bytes = [0xff, 0xfe, b'K', 0, b'B', 0, b'D', 0];
+        assert_eq!(decode(&bytes).unwrap(), "KBD");
+    }
+
+    #[test]
+    fn requires_shift_states() {
+        let err = parse("KBD\tTRAINER\t\"Trainer Test\"\nLAYOUT\n10\tQ\t1\tq\tQ\n")
+            .err()
+            .unwrap();
+        assert_eq!(err, "missing SHIFTSTATE section");
+    }
+}
@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use super::{AnsiKeyboardLayout, FormFactor, IsoKeyboardLayout, Key, NUMBER_ROW, NUMBER_ROW_SIZE};
+
+pub mod keylayout;
+mod keysym;
+pub mod klc;
+pub mod xkb;
+
+// Physical keys are named after their xkb key codes
+const ISO_ROWS: ([&str; 12], [&str; 12], [&str; 11]) = (
+    [
+        "AD01", "AD02", "AD03", "AD04", "AD05", "AD06", "AD07", "AD08", "AD09", "AD10", "AD11",
+        "AD12",
+    ],
+    [
+        "AC01", "AC02", "AC03", "AC04", "AC05", "AC06", "AC07", "AC08", "AC09", "AC10", "AC11",
+        "BKSL",
+    ],
+    [
+        "LSGT", "AB01", "AB02", "AB03", "AB04", "AB05", "AB06", "AB07", "AB08", "AB09", "AB10",
+    ],
+);
+
+const ANSI_ROWS: ([&str; 13], [&str; 11], [&str; 10]) = (
+    [
+        "AD01", "AD02", "AD03", "AD04", "AD05", "AD06", "AD07", "AD08", "AD09", "AD10", "AD11",
+        "AD12", "BKSL",
+    ],
+    [
+        "AC01", "AC02", "AC03", "AC04", "AC05", "AC06", "AC07", "AC08", "AC09", "AC10", "AC11",
+    ],
+    [
+        "AB01", "AB02", "AB03", "AB04", "AB05", "AB06", "AB07", "AB08", "AB09", "AB10",
+    ],
+);
+
+const NUMBER_KEYS: [&str; NUMBER_ROW_SIZE] = [
+    "TLDE", "AE01", "AE02", "AE03", "AE04", "AE05", "AE06", "AE07", "AE08", "AE09", "AE10", "AE11",
+    "AE12",
+];
+
+// The plain, shifted and AltGr symbol of each key
+type Layers<'a> = HashMap<&'a str, [Option<Symbol>; 3]>;
+
+// Dead keys are stored as their spacing accent
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Symbol {
+    Char(char),
+    Dead(char),
+}
+
+impl Symbol {
+    fn char(self) -> char {
+        match self {
+            Symbol::Char(ch) | Symbol::Dead(ch) => ch,
+        }
+    }
+}
+
+// The symbols of each physical key, one entry per shift level
+#[derive(Default)]
+pub struct KeyMap {
+    keys: HashMap<String, Vec<Option<Symbol>>>,
+    // ANSI keys that take their symbols from another key
+    ansi_aliases: HashMap<&'static str, &'static str>,
+}
+
+impl KeyMap {
+    pub fn insert(&mut self, key: &str, symbols: Vec<Option<Symbol>>) {
+        // The ISO key next to enter shares its code with the ANSI backslash
+        let key = match key {
+            "AC12" => "BKSL",
+            key => key,
+        };
+        self.keys.insert(key.to_string(), symbols);
+    }
+
+    pub fn alias_ansi(&mut self, key: &'static str, source: &'static str) {
+        self.ansi_aliases.insert(key, source);
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.keys.contains_key(key)
+    }
+
+    pub fn extend(&mut self, other: KeyMap) {
+        self.keys.extend(other.keys);
+        self.ansi_aliases.extend(other.ansi_aliases);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    fn symbol(&self, key: &str, form_factor: FormFactor, level: usize) -> Option<Symbol> {
+        let key = match form_factor {
+            FormFactor::Ansi => self.ansi_aliases.get(key).copied().unwrap_or(key),
+            FormFactor::Iso => key,
+        };
+        let symbol = self.keys.get(key)?.get(level).copied().flatten()?;
+        let ch = symbol.char();
+        (!ch.is_whitespace() && !ch.is_control()).then_some(symbol)
+    }
+
+    pub fn iso(&self, name: &str) -> IsoKeyboardLayout {
+        // Symbols on the letter rows take precedence over the number row
+        let keys = [&ISO_ROWS.0[..], &ISO_ROWS.1, &ISO_ROWS.2, &NUMBER_KEYS].concat();
+        let layers = self.layers(&keys, FormFactor::Iso);
+        IsoKeyboardLayout {
+            name: name.to_string(),
+            numbers: Self::row(NUMBER_KEYS, FormFactor::Iso, NUMBER_ROW, &layers),
+            row0: Self::row(ISO_ROWS.0, FormFactor::Iso, 0, &layers),
+            row1: Self::row(ISO_ROWS.1, FormFactor::Iso, 1, &layers),
+            row2: Self::row(ISO_ROWS.2, FormFactor::Iso, 2, &layers),
+            dead_keys: Self::dead_keys(&layers),
+            progression: Vec::new(),
+        }
+    }
+
+    pub fn ansi(&self, name: &str) -> AnsiKeyboardLayout {
+        let keys = [&ANSI_ROWS.0[..], &ANSI_ROWS.1, &ANSI_ROWS.2, &NUMBER_KEYS].concat();
+        let layers = self.layers(&keys, FormFactor::Ansi);
+        AnsiKeyboardLayout {
+            name: name.to_string(),
+            numbers: Self::row(NUMBER_KEYS, FormFactor::Ansi, NUMBER_ROW, &layers),
+            row0: Self::row(ANSI_ROWS.0, FormFactor::Ansi, 0, &layers),
+            row1: Self::row(ANSI_ROWS.1, FormFactor::Ansi, 1, &layers),
+            row2: Self::row(ANSI_ROWS.2, FormFactor::Ansi, 2, &layers),
+            dead_keys: Self::dead_keys(&layers),
+            progression: Vec::new(),
+        }
+    }
+
+    // Picks the plain, shifted and AltGr symbol of each key. Symbols on more
+    // than one key are only kept once, lower levels first.
+    fn layers<'a>(&self, keys: &[&'a str], form_factor: FormFactor) -> Layers<'a> {
+        let mut layers = Layers::new();
+        let mut seen = Vec::new();
+
+        for level in 0..3 {
+            for key in keys {
+                let symbols = layers.entry(key).or_default();
+                if level > 0 && symbols[0].is_none() {
+                    continue;
+                }
+                symbols[level] = self
+                    .symbol(key, form_factor, level)
+                    .filter(|symbol| !seen.contains(&symbol.char()));
+                seen.extend(symbols[level].map(Symbol::char));
+            }
+        }
+
+        layers
+    }
+
+    fn dead_keys(layers: &Layers) -> Vec<char> {
+        let mut dead_keys = layers
+            .values()
+            .flatten()
+            .filter_map(|symbol| match symbol {
+                Some(Symbol::Dead(ch)) => Some(*ch),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        dead_keys.sort_unstable();
+        dead_keys
+    }
+
+    fn row<const N: usize>(
+        keys: [&str; N],
+        form_factor: FormFactor,
+        row: usize,
+        layers: &Layers,
+    ) -> [Key; N] {
+        let mut col = 0;
+        keys.map(|key| {
+            let finger = form_factor.standard_finger(row, col);
+            col += 1;
+
+            match layers.get(key) {
+                Some([Some(base), shifted, altgr]) => Key::Char(
+                    base.char(),
+                    shifted.map(Symbol::char),
+                    altgr.map(Symbol::char),
+                    finger,
+                ),
+                _ => Key::None,
+            }
+        })
+    }
+}
@@ -0,0 +1,395 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use super::{keysym, KeyMap, Symbol};
+
+const SYSTEM_DIR: &str = "/usr/share/X11/xkb/symbols";
+const MAX_INCLUDE_DEPTH: usize = 16;
+
+#[derive(Clone, PartialEq, Eq)]
+enum Token {
+    Ident(String),
+    Str(String),
+    KeyName(String),
+    Punct(char),
+}
+
+struct Section {
+    name: String,
+    default: bool,
+    body: Vec<Token>,
+}
+
+// Loads a symbols section such as `us(colemak_dh)` or `./custom(variant)`.
+// Plain file names are looked up like xkb does, paths are relative to `dir`.
+pub fn load(spec: &str, dir: &Path) -> Result<KeyMap, String> {
+    let (file, section) = split_spec(spec);
+
+    let mut search = Vec::new();
+    if file.contains('/') {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            search.push(parent.to_path_buf());
+        }
+        let file = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| format!("\"{}\" is not a file", file))?
+            .to_string();
+        search.extend(search_dirs());
+        load_section(&file, section, &search, 0)
+    } else {
+        search.extend(search_dirs());
+        load_section(file, section, &search, 0)
+    }
+}
+
+fn search_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(config) = dirs::config_dir() {
+        dirs.push(config.join("xkb").join("symbols"));
+    }
+    if let Some(home) = dirs::home_dir() {
+        dirs.push(home.join(".xkb").join("symbols"));
+    }
+    dirs.push(PathBuf::from(SYSTEM_DIR));
+    dirs
+}
+
+fn split_spec(spec: &str) -> (&str, Option<&str>) {
+    let spec = spec.trim();
+    match spec.strip_suffix(')').and_then(|spec| spec.split_once('(')) {
+        Some((file, section)) => (file, Some(section)),
+        None => (spec, None),
+    }
+}
+
+fn load_section(
+    file: &str,
+    section: Option<&str>,
+    search: &[PathBuf],
+    depth: usize,
+) -> Result<KeyMap, String> {
+    if depth > MAX_INCLUDE_DEPTH {
+        return Err(String::from("includes are nested too deeply"));
+    }
+
+    let path = search
+        .iter()
+        .map(|dir| dir.join(file))
+        .find(|path| path.is_file())
+        .ok_or_else(|| format!("could not find symbols file \"{}\"", file))?;
+    let contents = fs::read_to_string(&path)
+        .map_err(|err| format!("could not read {}: {}", path.display(), err))?;
+    let sections = parse_sections(&tokenize(&contents)?)
+        .map_err(|err| format!("{}: {}", path.display(), err))?;
+
+    let found = match section {
+        Some(name) => sections.into_iter().find(|s| s.name == name),
+        None => {
+            let idx = sections.iter().position(|s| s.default).unwrap_or(0);
+            sections.into_iter().nth(idx)
+        }
+    };
+    let Some(found) = found else {
+        return Err(format!(
+            "{} has no section \"{}\"",
+            path.display(),
+            section.unwrap_or("default")
+        ));
+    };
+
+    interpret(&found.body, search, depth)
+}
+
+fn interpret(body: &[Token], search: &[PathBuf], depth: usize) -> Result<KeyMap, String> {
+    let mut keys = KeyMap::default();
+    let mut i = 0;
+
+    while i < body.len() {
+        match (&body[i], body.get(i + 1)) {
+            (Token::Ident(stmt), Some(Token::Str(includes)))
+                if matches!(
+                    stmt.as_str(),
+                    "include" | "augment" | "override" | "replace"
+                ) =>
+            {
+                for include in includes.split(['+', '|']) {
+                    // Includes mapped onto other groups don't affect the first group
+                    let include = match include.split_once(':') {
+                        Some((include, "1")) => include,
+                        Some(_) => continue,
+                        None => include,
+                    };
+                    let (file, section) = split_spec(include);
+                    let included = load_section(file, section, search, depth + 1)?;
+
+                    if stmt == "augment" {
+                        for (key, symbols) in included.keys {
+                            if !keys.contains(&key) {
+                                keys.insert(&key, symbols);
+                            }
+                        }
+                    } else {
+                        keys.extend(included);
+                    }
+                }
+                i += 2;
+            }
+            (Token::Ident(merge), Some(Token::Ident(stmt)))
+                if stmt == "key"
+                    && matches!(merge.as_str(), "augment" | "override" | "replace") =>
+            {
+                i += 1;
+            }
+            (Token::Ident(stmt), Some(Token::KeyName(key))) if stmt == "key" => {
+                let end = matching_brace(body, i + 2)?;
+                if let Some(symbols) = key_symbols(&body[i + 3..end]) {
+                    keys.insert(key, symbols);
+                }
+                i = end + 1;
+            }
+            _ => {
+                // Skip over statements that don't define symbols, e.g. `name[Group1] = "..."`
+                let mut depth = 0;
+                while i < body.len() {
+                    match body[i] {
+                        Token::Punct('{') | Token::Punct('[') | Token::Punct('(') => depth += 1,
+                        Token::Punct('}') | Token::Punct(']') | Token::Punct(')') => depth -= 1,
+                        Token::Punct(';') if depth <= 0 => break,
+                        _ => {}
+                    }
+                    i += 1;
+                }
+                i += 1;
+            }
+        }
+    }
+
+    Ok(keys)
+}
+
+// Extracts the symbols of the first group from a key definition body like
+// `[ q, Q ]` or `type[Group1] = "FOUR_LEVEL", symbols[Group1] = [ q, Q ]`
+fn key_symbols(body: &[Token]) -> Option<Vec<Option<Symbol>>> {
+    let mut symbols = None;
+
+    for item in split_top_level(body) {
+        let list = match item {
+            [Token::Punct('['), ..] => {
+                if symbols.is_some() {
+                    continue;
+                }
+                item
+            }
+            [Token::Ident(field), Token::Punct('['), Token::Ident(group), Token::Punct(']'), Token::Punct('='), list @ ..]
+                if field == "symbols" =>
+            {
+                if !group.eq_ignore_ascii_case("group1") {
+                    continue;
+                }
+                list
+            }
+            _ => continue,
+        };
+
+        let inner = match list {
+            [Token::Punct('['), inner @ .., Token::Punct(']')] => inner,
+            _ => continue,
+        };
+        symbols = Some(
+            split_top_level(inner)
+                .into_iter()
+                .map(|sym| match sym {
+                    [Token::Ident(name)] => keysym::to_char(name)
+                        .map(Symbol::Char)
+                        .or_else(|| keysym::dead_key(name).map(Symbol::Dead)),
+                    _ => None,
+                })
+                .collect(),
+        );
+    }
+
+    symbols
+}
+
+fn split_top_level(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut items = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+
+    for (i, token) in tokens.iter().enumerate() {
+        match token {
+            Token::Punct('{') | Token::Punct('[') | Token::Punct('(') => depth += 1,
+            Token::Punct('}') | Token::Punct(']') | Token::Punct(')') => depth -= 1,
+            Token::Punct(',') if depth == 0 => {
+                items.push(&tokens[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    if start < tokens.len() {
+        items.push(&tokens[start..]);
+    }
+    items
+}
+
+fn matching_brace(tokens: &[Token], open: usize) -> Result<usize, String> {
+    if tokens.get(open) != Some(&Token::Punct('{')) {
+        return Err(String::from("expected '{'"));
+    }
+
+    let mut depth = 0;
+    for (i, token) in tokens.iter().enumerate().skip(open) {
+        match token {
+            Token::Punct('{') => depth += 1,
+            Token::Punct('}') => {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    Err(String::from("missing '}'"))
+}
+
+fn parse_sections(tokens: &[Token]) -> Result<Vec<Section>, String> {
+    let mut sections = Vec::new();
+    let mut default = false;
+    let mut i = 0;
+
+    while i < tokens.len() {
+        match &tokens[i] {
+            Token::Ident(flag) if flag == "default" => default = true,
+            Token::Ident(kind) if kind == "xkb_symbols" => {
+                let Some(Token::Str(name)) = tokens.get(i + 1) else {
+                    return Err(String::from("expected a section name after xkb_symbols"));
+                };
+                let end = matching_brace(tokens, i + 2)?;
+                sections.push(Section {
+                    name: name.clone(),
+                    default,
+                    body: tokens[i + 3..end].to_vec(),
+                });
+                default = false;
+                i = end;
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+
+    Ok(sections)
+}
+
+fn tokenize(src: &str) -> Result<Vec<Token>, String> {
+    let mut tokens = Vec::new();
+    let mut chars = src.chars().peekable();
+
+    while let Some(ch) = chars.next() {
+        match ch {
+            _ if ch.is_whitespace() => {}
+            '/' if chars.peek() == Some(&'/') => {
+                for ch in chars.by_ref() {
+                    if ch == '\n' {
+                        break;
+                    }
+                }
+            }
+            '/' if chars.peek() == Some(&'*') => {
+                chars.next();
+                let mut last = ' ';
+                for ch in chars.by_ref() {
+                    if last == '*' && ch == '/' {
+                        break;
+                    }
+                    last = ch;
+                }
+            }
+            '"' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some(ch) => value.push(ch),
+                        None => return Err(String::from("unterminated string")),
+                    }
+                }
+                tokens.push(Token::Str(value));
+            }
+            '<' => {
+                let mut value = String::new();
+                loop {
+                    match chars.next() {
+                        Some('>') => break,
+                        Some(ch) => value.push(ch),
+                        None => return Err(String::from("unterminated key name")),
+                    }
+                }
+                tokens.push(Token::KeyName(value));
+            }
+            _ if ch.is_alphanumeric() || ch == '_' => {
+                let mut value = String::from(ch);
+                while let Some(&next) = chars.peek() {
+                    if !(next.is_alphanumeric() || next == '_') {
+                        break;
+                    }
+                    value.push(next);
+                    chars.next();
+                }
+                tokens.push(Token::Ident(value));
+            }
+            _ => tokens.push(Token::Punct(ch)),
+        }
+    }
+
+    Ok(tokens)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixtures() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/xkb")
+    }
+
+    #[test]
+    fn resolves_includes_and_overrides() {
+        let keys = load("./trainer_custom", &fixtures()).unwrap();
+
+        let symbols = |key: &str| keys.keys[key].clone();
+        let chars =
+            |chars: &[char]| -> Vec<_> { chars.iter().map(|ch| Some(Symbol::Char(*ch))).collect() };
+        assert_eq!(symbols("AD01"), chars(&['f', 'F']));
+        assert_eq!(symbols("AD02"), chars(&['w', 'W', 'å', 'Å']));
+        assert_eq!(symbols("AC01"), chars(&['a', 'A', 'ä', 'Ä']));
+        assert_eq!(symbols("AC02"), chars(&['s', 'S']));
+        assert_eq!(symbols("AB01"), chars(&['z', 'Z']));
+        assert_eq!(
+            symbols("AC11"),
+            vec![Some(Symbol::Dead('´')), Some(Symbol::Dead('`'))]
+        );
+    }
+
+    #[test]
+    fn reports_missing_include() {
+        let err = load("./trainer_custom(missing)", &fixtures())
+            .err()
+            .unwrap();
+        assert_eq!(err, "could not find symbols file \"trainer_nonexistent\"");
+    }
+
+    #[test]
+    fn reports_missing_section() {
+        let err = load("./trainer_custom(colemak)", &fixtures())
+            .err()
+            .unwrap();
+        assert!(err.ends_with("trainer_custom has no section \"colemak\""));
+    }
+}
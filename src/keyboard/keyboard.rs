@@ -1,5 +1,12 @@
 use std::collections::{HashMap, HashSet};
 
+use serde::{Deserialize, Serialize};
+
+use super::{
+    compose::{compose, decompose},
+    layouts,
+};
+
 pub enum KeyboardLayouts {
     Iso {
         source: IsoKeyboardLayout,
@@ -13,6 +20,32 @@ pub enum KeyboardLayouts {
 
 impl KeyboardLayouts {
     pub fn allowed_target_letters(&self, level: Level) -> HashSet<char> {
+        if level > Level::Six {
+            // Level seven adds the punctuation on the letter rows, level eight the number row
+            let rows = if level == Level::Seven { 3 } else { 4 };
+            let mut letters = self.allowed_target_letters(Level::Six);
+            for row in self.target_layout().rows().into_iter().take(rows) {
+                for key in row {
+                    if let Key::Char(ch, _, _, _) = key {
+                        letters.insert(*ch);
+                    }
+                }
+            }
+            return letters;
+        }
+
+        let progression = match self {
+            KeyboardLayouts::Iso { source: _, target } => &target.progression,
+            KeyboardLayouts::Ansi { source: _, target } => &target.progression,
+        };
+        if !progression.is_empty() {
+            return progression
+                .iter()
+                .take(level as usize + 1)
+                .flat_map(|letters| letters.chars())
+                .collect();
+        }
+
         let (indices, row0, row1, row2) = match self {
             KeyboardLayouts::Iso {
                 source: _,
@@ -37,8 +70,8 @@ impl KeyboardLayouts {
 
         for i in indices.row0 {
             match row0[*i as usize] {
-                Key::Char(ch, _) => {
-                    letters.insert(ch.to_ascii_lowercase());
+                Key::Char(ch, _, _, _) => {
+                    letters.insert(ch);
                 }
                 _ => {}
             }
@@ -46,8 +79,8 @@ impl KeyboardLayouts {
 
         for i in indices.row1 {
             match row1[*i as usize] {
-                Key::Char(ch, _) => {
-                    letters.insert(ch.to_ascii_lowercase());
+                Key::Char(ch, _, _, _) => {
+                    letters.insert(ch);
                 }
                 _ => {}
             }
@@ -55,8 +88,8 @@ impl KeyboardLayouts {
 
         for i in indices.row2 {
             match row2[*i as usize] {
-                Key::Char(ch, _) => {
-                    letters.insert(ch.to_ascii_lowercase());
+                Key::Char(ch, _, _, _) => {
+                    letters.insert(ch);
                 }
                 _ => {}
             }
@@ -65,6 +98,52 @@ impl KeyboardLayouts {
         letters
     }
 
+    pub fn unlocked_target_letters(&self, level: Level) -> Vec<char> {
+        let allowed = self.allowed_target_letters(level);
+        let previous = level
+            .previous()
+            .map(|previous| self.allowed_target_letters(previous))
+            .unwrap_or_default();
+
+        let mut unlocked = allowed.difference(&previous).copied().collect::<Vec<_>>();
+        unlocked.sort();
+        unlocked
+    }
+
+    pub fn find(form_factor: FormFactor, source: &str, target: &str) -> Option<Self> {
+        match form_factor {
+            FormFactor::Iso => Some(KeyboardLayouts::Iso {
+                source: layouts::iso(source)?,
+                target: layouts::iso(target)?,
+            }),
+            FormFactor::Ansi => Some(KeyboardLayouts::Ansi {
+                source: layouts::ansi(source)?,
+                target: layouts::ansi(target)?,
+            }),
+        }
+    }
+
+    pub fn form_factor(&self) -> FormFactor {
+        match self {
+            KeyboardLayouts::Iso { .. } => FormFactor::Iso,
+            KeyboardLayouts::Ansi { .. } => FormFactor::Ansi,
+        }
+    }
+
+    pub fn source_name(&self) -> &str {
+        match self {
+            KeyboardLayouts::Iso { source, target: _ } => &source.name,
+            KeyboardLayouts::Ansi { source, target: _ } => &source.name,
+        }
+    }
+
+    pub fn target_name(&self) -> &str {
+        match self {
+            KeyboardLayouts::Iso { source: _, target } => &target.name,
+            KeyboardLayouts::Ansi { source: _, target } => &target.name,
+        }
+    }
+
     pub fn target_layout(&self) -> AnyKeyboardLayout {
         match self {
             KeyboardLayouts::Iso { source: _, target } => AnyKeyboardLayout::Iso(target),
@@ -72,29 +151,193 @@ impl KeyboardLayouts {
         }
     }
 
-    pub fn layout_mapper(&self) -> LayoutMapper {
-        LayoutMapper::from(self)
+    pub fn layout_mapper(&self, mode: InputMode) -> LayoutMapper {
+        match mode {
+            InputMode::Remap => LayoutMapper::from(self),
+            InputMode::Passthrough => LayoutMapper::passthrough(),
+        }
+    }
+}
+
+// Letters like 'ß' have no single character upper case, they are kept as is
+pub fn lowercase(ch: char) -> char {
+    let mut lower = ch.to_lowercase();
+    match (lower.next(), lower.next()) {
+        (Some(lower), None) => lower,
+        _ => ch,
     }
 }
 
+pub fn uppercase(ch: char) -> char {
+    let mut upper = ch.to_uppercase();
+    match (upper.next(), upper.next()) {
+        (Some(upper), None) => upper,
+        _ => ch,
+    }
+}
+
+// Whether key presses arrive on the source layout and are remapped to the
+// target, or the OS already uses the target layout
+#[derive(Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum InputMode {
+    #[default]
+    Remap,
+    Passthrough,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FormFactor {
+    Iso,
+    Ansi,
+}
+
+impl FormFactor {
+    pub fn row_sizes(self) -> [usize; 3] {
+        match self {
+            FormFactor::Iso => [12, 12, 11],
+            FormFactor::Ansi => [13, 11, 10],
+        }
+    }
+
+    pub fn standard_finger(self, row: usize, col: usize) -> Finger {
+        use Finger::*;
+
+        // The ISO layout has an additional pinky key left of the bottom row,
+        // the number row has one left of 1
+        let col = match (self, row) {
+            (FormFactor::Iso, 2) | (_, NUMBER_ROW) if col == 0 => return Pinky,
+            (FormFactor::Iso, 2) | (_, NUMBER_ROW) => col - 1,
+            _ => col,
+        };
+
+        match col {
+            0 => Pinky,
+            1 => Ring,
+            2 => Middle,
+            3..=6 => Index,
+            7 => Middle,
+            8 => Ring,
+            _ => Pinky,
+        }
+    }
+}
+
+// The number row comes after the letter rows so they keep their indices
+pub const NUMBER_ROW: usize = 3;
+pub const NUMBER_ROW_SIZE: usize = 13;
+
+#[derive(Clone)]
 pub struct IsoKeyboardLayout {
+    pub name: String,
+    pub numbers: [Key; NUMBER_ROW_SIZE],
     pub row0: [Key; 12],
     pub row1: [Key; 12],
     pub row2: [Key; 11],
+    // Characters that are combined with the next key press
+    pub dead_keys: Vec<char>,
+    pub progression: Vec<String>,
 }
 
+#[derive(Clone)]
 pub struct AnsiKeyboardLayout {
+    pub name: String,
+    pub numbers: [Key; NUMBER_ROW_SIZE],
     pub row0: [Key; 13],
     pub row1: [Key; 11],
     pub row2: [Key; 10],
+    pub dead_keys: Vec<char>,
+    pub progression: Vec<String>,
 }
 
+#[derive(Clone, Copy)]
 pub enum AnyKeyboardLayout<'a> {
     Iso(&'a IsoKeyboardLayout),
     Ansi(&'a AnsiKeyboardLayout),
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+impl AnyKeyboardLayout<'_> {
+    pub fn rows(&self) -> [&[Key]; 4] {
+        match self {
+            AnyKeyboardLayout::Iso(layout) => {
+                [&layout.row0, &layout.row1, &layout.row2, &layout.numbers]
+            }
+            AnyKeyboardLayout::Ansi(layout) => {
+                [&layout.row0, &layout.row1, &layout.row2, &layout.numbers]
+            }
+        }
+    }
+
+    pub fn find(&self, ch: char) -> Option<(KeyPosition, Finger)> {
+        for (row, keys) in self.rows().into_iter().enumerate() {
+            for (col, key) in keys.iter().enumerate() {
+                if let Key::Char(base, shifted, altgr, finger) = key {
+                    if *base == ch || *shifted == Some(ch) || *altgr == Some(ch) {
+                        return Some((KeyPosition::new(row, col), *finger));
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    // Returns the characters on the shift layer of the keys producing `letters`
+    pub fn shifted(&self, letters: &HashSet<char>) -> HashSet<char> {
+        self.rows()
+            .into_iter()
+            .flatten()
+            .filter_map(|key| match key {
+                Key::Char(base, Some(shifted), _, _) if letters.contains(base) => Some(*shifted),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn dead_keys(&self) -> &[char] {
+        match self {
+            AnyKeyboardLayout::Iso(layout) => &layout.dead_keys,
+            AnyKeyboardLayout::Ansi(layout) => &layout.dead_keys,
+        }
+    }
+
+    // Returns the characters the dead keys among `letters` combine into
+    pub fn composed(&self, letters: &HashSet<char>) -> HashSet<char> {
+        self.dead_keys()
+            .iter()
+            .filter(|dead| letters.contains(dead))
+            .flat_map(|dead| letters.iter().filter_map(|letter| compose(*dead, *letter)))
+            .collect()
+    }
+
+    pub fn hand(&self, position: KeyPosition) -> Hand {
+        // The ISO layout has an additional key left of the bottom row, and
+        // the number row starts with the key left of 1
+        let split = match (self, position.row) {
+            (AnyKeyboardLayout::Iso(_), 2) => 6,
+            (_, NUMBER_ROW) => 6,
+            _ => 5,
+        };
+
+        if position.col < split {
+            Hand::Left
+        } else {
+            Hand::Right
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct KeyPosition {
+    pub row: usize,
+    pub col: usize,
+}
+
+impl KeyPosition {
+    pub fn new(row: usize, col: usize) -> Self {
+        Self { row, col }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum Level {
     One,
     Two,
@@ -102,6 +345,35 @@ pub enum Level {
     Four,
     Five,
     Six,
+    // Punctuation on the letter rows
+    Seven,
+    // The number row
+    Eight,
+}
+
+impl Level {
+    pub const ALL: [Level; 8] = [
+        Level::One,
+        Level::Two,
+        Level::Three,
+        Level::Four,
+        Level::Five,
+        Level::Six,
+        Level::Seven,
+        Level::Eight,
+    ];
+
+    pub fn number(self) -> usize {
+        self as usize + 1
+    }
+
+    pub fn previous(self) -> Option<Self> {
+        (self as usize).checked_sub(1).map(|idx| Self::ALL[idx])
+    }
+
+    pub fn next(self) -> Option<Self> {
+        Self::ALL.get(self as usize + 1).copied()
+    }
 }
 
 struct KeyIndices {
@@ -184,10 +456,22 @@ impl KeyIndices {
     }
 }
 
+// A key with its unshifted, shifted and AltGr character
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
 pub enum Key {
     None,
-    Char(char, Finger),
+    Char(char, Option<char>, Option<char>, Finger),
+}
+
+impl Key {
+    // Letters are labeled in upper case like on a real keyboard
+    pub fn label(self) -> Option<char> {
+        match self {
+            Key::None => None,
+            Key::Char(base, _, _, _) if base.is_alphabetic() => Some(uppercase(base)),
+            Key::Char(base, _, _, _) => Some(base),
+        }
+    }
 }
 
 #[derive(Clone, Copy, Hash, PartialEq, Eq)]
@@ -199,7 +483,32 @@ pub enum Finger {
     Thumb,
 }
 
-pub struct LayoutMapper(HashMap<char, Key>);
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub enum Hand {
+    Left,
+    Right,
+}
+
+// A key named after its character on a US layout, which terminals with the
+// kitty keyboard protocol report independent of the OS layout
+#[derive(Clone, Copy)]
+pub struct PhysicalKey {
+    pub base: char,
+    pub shifted: bool,
+    pub altgr: bool,
+    pub caps_lock: bool,
+}
+
+pub struct LayoutMapper {
+    map: HashMap<char, Option<char>>,
+    // US layout characters to the target keys at their position
+    physical: HashMap<char, Key>,
+    source_dead_keys: Vec<char>,
+    target_dead_keys: Vec<char>,
+    // The target dead key waiting for the next key press
+    pending: Option<char>,
+    mode: InputMode,
+}
 
 impl LayoutMapper {
     pub fn from(layouts: &KeyboardLayouts) -> Self {
@@ -216,7 +525,19 @@ impl LayoutMapper {
         Self::map_row(source.row0, target.row0, &mut map);
         Self::map_row(source.row1, target.row1, &mut map);
         Self::map_row(source.row2, target.row2, &mut map);
-        Self(map)
+        Self::map_row(source.numbers, target.numbers, &mut map);
+
+        let us = layouts::qwerty::iso();
+        let mut physical = HashMap::new();
+        Self::physical_row(us.row0, target.row0, &mut physical);
+        Self::physical_row(us.row1, target.row1, &mut physical);
+        Self::physical_row(us.row2, target.row2, &mut physical);
+        Self::physical_row(us.numbers, target.numbers, &mut physical);
+
+        Self {
+            physical,
+            ..Self::new(map, &source.dead_keys, &target.dead_keys)
+        }
     }
 
     pub fn from_ansi(source: &AnsiKeyboardLayout, target: &AnsiKeyboardLayout) -> Self {
@@ -224,24 +545,152 @@ impl LayoutMapper {
         Self::map_row(source.row0, target.row0, &mut map);
         Self::map_row(source.row1, target.row1, &mut map);
         Self::map_row(source.row2, target.row2, &mut map);
-        Self(map)
+        Self::map_row(source.numbers, target.numbers, &mut map);
+
+        let us = layouts::qwerty::ansi();
+        let mut physical = HashMap::new();
+        Self::physical_row(us.row0, target.row0, &mut physical);
+        Self::physical_row(us.row1, target.row1, &mut physical);
+        Self::physical_row(us.row2, target.row2, &mut physical);
+        Self::physical_row(us.numbers, target.numbers, &mut physical);
+
+        Self {
+            physical,
+            ..Self::new(map, &source.dead_keys, &target.dead_keys)
+        }
+    }
+
+    fn new(
+        map: HashMap<char, Option<char>>,
+        source_dead_keys: &[char],
+        target_dead_keys: &[char],
+    ) -> Self {
+        Self {
+            map,
+            physical: HashMap::new(),
+            source_dead_keys: source_dead_keys.to_vec(),
+            target_dead_keys: target_dead_keys.to_vec(),
+            pending: None,
+            mode: InputMode::Remap,
+        }
     }
 
-    fn map_row<const N: usize>(source: [Key; N], target: [Key; N], map: &mut HashMap<char, Key>) {
+    // Takes characters as they are, the OS handles dead keys itself
+    pub fn passthrough() -> Self {
+        Self {
+            mode: InputMode::Passthrough,
+            ..Self::new(HashMap::new(), &[], &[])
+        }
+    }
+
+    fn map_row<const N: usize>(
+        source: [Key; N],
+        target: [Key; N],
+        map: &mut HashMap<char, Option<char>>,
+    ) {
         for i in 0..N {
             let source = source[i];
             let target = target[i];
 
-            if let Key::Char(chr, _) = source {
-                map.insert(chr.to_ascii_lowercase(), target);
+            let Key::Char(base, shifted, altgr, _) = source else {
+                continue;
+            };
+            let (target_base, target_shifted, target_altgr) = match target {
+                Key::Char(base, shifted, altgr, _) => (Some(base), shifted, altgr),
+                Key::None => (None, None, None),
+            };
+
+            // Unshifted characters win if a layout has a character on several layers
+            if let Some(shifted) = shifted {
+                map.entry(shifted).or_insert(target_shifted);
             }
+            if let Some(altgr) = altgr {
+                map.entry(altgr).or_insert(target_altgr);
+            }
+            map.insert(base, target_base);
         }
     }
 
-    pub fn map(&self, key: char) -> Option<char> {
-        match self.0.get(&key).copied().unwrap_or(Key::None) {
-            Key::None => None,
-            Key::Char(chr, _) => Some(chr.to_ascii_lowercase()),
+    fn physical_row<const N: usize>(us: [Key; N], target: [Key; N], map: &mut HashMap<char, Key>) {
+        for (us, target) in us.into_iter().zip(target) {
+            if let Key::Char(base, _, _, _) = us {
+                map.insert(base, target);
+            }
+        }
+    }
+
+    // Maps the key at the same position on the target layout, or returns
+    // `None` if the key is unknown and the character has to be mapped instead
+    pub fn map_physical(&mut self, key: PhysicalKey) -> Option<Vec<char>> {
+        let target = match *self.physical.get(&key.base)? {
+            Key::Char(_, _, Some(altgr), _) if key.altgr => Some(altgr),
+            Key::Char(base, shifted, _, _) if !key.altgr => {
+                // Caps lock works like shift on the letters of the target layout
+                let caps_lock = key.caps_lock && base.is_alphabetic();
+                if key.shifted != caps_lock {
+                    shifted
+                } else {
+                    Some(base)
+                }
+            }
+            _ => None,
+        };
+        Some(target.map(|ch| self.press(ch)).unwrap_or_default())
+    }
+
+    // Returns the characters the key produces on the target layout, which is
+    // nothing while a dead key waits for the next key
+    pub fn map(&mut self, key: char) -> Vec<char> {
+        if self.mode == InputMode::Passthrough {
+            return vec![key];
         }
+
+        if key == ' ' {
+            return self.pending.take().into_iter().collect();
+        }
+
+        if let Some(target) = self.map.get(&key).copied() {
+            return target.map(|ch| self.press(ch)).unwrap_or_default();
+        }
+
+        // The OS already combined a dead key of the source layout with the
+        // following key, so both are mapped on their own
+        match decompose(key) {
+            Some((accent, letter)) if self.source_dead_keys.contains(&accent) => {
+                let mut chars = self.map(accent);
+                chars.extend(self.map(letter));
+                chars
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    fn press(&mut self, ch: char) -> Vec<char> {
+        let Some(accent) = self.pending.take() else {
+            if self.target_dead_keys.contains(&ch) {
+                self.pending = Some(ch);
+                return Vec::new();
+            }
+            return vec![ch];
+        };
+
+        // Pressing the dead key twice types the accent on its own, like space
+        match compose(accent, ch) {
+            Some(composed) => vec![composed],
+            None if ch == accent => vec![accent],
+            None => {
+                let mut chars = vec![accent];
+                chars.extend(self.press(ch));
+                chars
+            }
+        }
+    }
+
+    pub fn pending(&self) -> Option<char> {
+        self.pending
+    }
+
+    pub fn cancel(&mut self) {
+        self.pending = None;
     }
 }
@@ -0,0 +1,540 @@
+use std::{
+    fmt, fs,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use super::{
+    import::{keylayout, klc, xkb},
+    lowercase, uppercase, AnsiKeyboardLayout, AnyKeyboardLayout, Finger, FormFactor,
+    IsoKeyboardLayout, Key, NUMBER_ROW, NUMBER_ROW_SIZE,
+};
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LayoutFile {
+    name: String,
+    xkb: Option<String>,
+    klc: Option<String>,
+    keylayout: Option<String>,
+    progression: Option<Vec<String>>,
+    dead_keys: Option<String>,
+    iso: Option<LayoutSection>,
+    ansi: Option<LayoutSection>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LayoutSection {
+    rows: Vec<String>,
+    fingers: Option<Vec<String>>,
+    shifted: Option<Vec<String>>,
+    altgr: Option<Vec<String>>,
+    numbers: Option<String>,
+    number_fingers: Option<String>,
+    shifted_numbers: Option<String>,
+    altgr_numbers: Option<String>,
+}
+
+pub struct LoadedLayout {
+    pub name: String,
+    pub iso: Option<IsoKeyboardLayout>,
+    pub ansi: Option<AnsiKeyboardLayout>,
+}
+
+#[derive(Debug)]
+pub struct LayoutFileError {
+    path: PathBuf,
+    message: String,
+}
+
+impl LayoutFileError {
+    pub fn new(path: &Path, message: impl Into<String>) -> Self {
+        Self {
+            path: path.to_path_buf(),
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for LayoutFileError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+pub fn layouts_dir() -> Option<PathBuf> {
+    Some(dirs::config_dir()?.join("colemak-trainer").join("layouts"))
+}
+
+pub fn load_layout_files(dir: &Path) -> (Vec<(PathBuf, LoadedLayout)>, Vec<LayoutFileError>) {
+    let mut layouts = Vec::new();
+    let mut errors = Vec::new();
+
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(_) => return (layouts, errors),
+    };
+
+    let mut paths = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    for path in paths {
+        let result = fs::read_to_string(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|contents| parse_layout_file(&contents, dir));
+
+        match result {
+            Ok(layout) => layouts.push((path, layout)),
+            Err(message) => errors.push(LayoutFileError::new(&path, message)),
+        }
+    }
+
+    (layouts, errors)
+}
+
+pub fn parse_layout_file(contents: &str, dir: &Path) -> Result<LoadedLayout, String> {
+    let file: LayoutFile = toml::from_str(contents).map_err(|err| {
+        let message = err.message().trim().replace('\n', ", ");
+        match err.span() {
+            Some(span) => {
+                let line = contents[..span.start].matches('\n').count() + 1;
+                format!("line {}: {}", line, message)
+            }
+            None => message,
+        }
+    })?;
+
+    let name = file.name.trim().to_string();
+    if name.is_empty() {
+        return Err(String::from("the layout name must not be empty"));
+    }
+    let imported = match (&file.xkb, &file.klc, &file.keylayout) {
+        (None, None, None) => None,
+        (Some(spec), None, None) => Some(("xkb", spec, xkb::load(spec, dir))),
+        (None, Some(path), None) => Some(("klc", path, klc::load(&dir.join(path)))),
+        (None, None, Some(path)) => Some(("keylayout", path, keylayout::load(&dir.join(path)))),
+        _ => {
+            return Err(String::from(
+                "only one of xkb, klc and keylayout can be imported",
+            ))
+        }
+    };
+    let imported = match imported {
+        Some((kind, source, keys)) => {
+            let keys = keys.map_err(|err| format!("{} \"{}\": {}", kind, source, err))?;
+            if keys.is_empty() {
+                return Err(format!("{} \"{}\" does not define any keys", kind, source));
+            }
+            Some(keys)
+        }
+        None => None,
+    };
+
+    if file.iso.is_none() && file.ansi.is_none() && imported.is_none() {
+        return Err(String::from(
+            "the layout needs an [iso] or [ansi] section, or a layout to import",
+        ));
+    }
+
+    let mut iso = match &file.iso {
+        Some(section) => {
+            let ([row0, row1, row2], numbers) = parse_section(section, FormFactor::Iso, "iso")?;
+            Some(IsoKeyboardLayout {
+                name: name.clone(),
+                numbers: to_array(numbers),
+                row0: to_array(row0),
+                row1: to_array(row1),
+                row2: to_array(row2),
+                dead_keys: Vec::new(),
+                progression: Vec::new(),
+            })
+        }
+        None => imported.as_ref().map(|keys| keys.iso(&name)),
+    };
+
+    let mut ansi = match &file.ansi {
+        Some(section) => {
+            let ([row0, row1, row2], numbers) = parse_section(section, FormFactor::Ansi, "ansi")?;
+            Some(AnsiKeyboardLayout {
+                name: name.clone(),
+                numbers: to_array(numbers),
+                row0: to_array(row0),
+                row1: to_array(row1),
+                row2: to_array(row2),
+                dead_keys: Vec::new(),
+                progression: Vec::new(),
+            })
+        }
+        None => imported.as_ref().map(|keys| keys.ansi(&name)),
+    };
+
+    // Each level lists the letters it unlocks, e.g. `["arstneio", "dh"]`
+    let progression = file
+        .progression
+        .unwrap_or_default()
+        .iter()
+        .map(|letters| {
+            letters
+                .chars()
+                .filter(|ch| !ch.is_whitespace())
+                .map(lowercase)
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    if let Some(layout) = &mut iso {
+        check_progression(&progression, AnyKeyboardLayout::Iso(layout), "iso")?;
+        layout.progression = progression.clone();
+    }
+    if let Some(layout) = &mut ansi {
+        check_progression(&progression, AnyKeyboardLayout::Ansi(layout), "ansi")?;
+        layout.progression = progression.clone();
+    }
+
+    // Dead keys listed in the file replace the ones of an imported layout
+    if let Some(dead_keys) = &file.dead_keys {
+        let dead_keys = dead_keys
+            .split_whitespace()
+            .map(|token| {
+                parse_char(token)
+                    .ok_or_else(|| format!("dead key \"{}\" is not a single character", token))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if let Some(layout) = &mut iso {
+            check_dead_keys(&dead_keys, AnyKeyboardLayout::Iso(layout), "iso")?;
+            layout.dead_keys = dead_keys.clone();
+        }
+        if let Some(layout) = &mut ansi {
+            check_dead_keys(&dead_keys, AnyKeyboardLayout::Ansi(layout), "ansi")?;
+            layout.dead_keys = dead_keys.clone();
+        }
+    }
+
+    Ok(LoadedLayout { name, iso, ansi })
+}
+
+fn check_dead_keys(
+    dead_keys: &[char],
+    layout: AnyKeyboardLayout,
+    label: &str,
+) -> Result<(), String> {
+    match dead_keys.iter().find(|ch| layout.find(**ch).is_none()) {
+        Some(ch) => Err(format!(
+            "dead key '{}' is not on the [{}] keyboard",
+            ch, label
+        )),
+        None => Ok(()),
+    }
+}
+
+fn check_progression(
+    progression: &[String],
+    layout: AnyKeyboardLayout,
+    label: &str,
+) -> Result<(), String> {
+    for (level, letters) in progression.iter().enumerate() {
+        if let Some(ch) = letters.chars().find(|ch| layout.find(*ch).is_none()) {
+            return Err(format!(
+                "progression level {}: '{}' is not on the [{}] keyboard",
+                level + 1,
+                ch,
+                label
+            ));
+        }
+    }
+    Ok(())
+}
+
+// The whitespace separated tokens of one keyboard row on each layer
+struct RowTokens<'a> {
+    keys: &'a str,
+    shifted: Option<&'a str>,
+    altgr: Option<&'a str>,
+    fingers: Option<&'a str>,
+}
+
+fn parse_section(
+    section: &LayoutSection,
+    form_factor: FormFactor,
+    label: &str,
+) -> Result<([Vec<Key>; 3], Vec<Key>), String> {
+    let sizes = form_factor.row_sizes();
+    if section.rows.len() != sizes.len() {
+        return Err(format!(
+            "[{}] needs exactly {} rows, found {}",
+            label,
+            sizes.len(),
+            section.rows.len()
+        ));
+    }
+    let layers = [
+        ("finger", &section.fingers),
+        ("shifted", &section.shifted),
+        ("altgr", &section.altgr),
+    ];
+    for (name, rows) in layers {
+        if let Some(rows) = rows {
+            if rows.len() != sizes.len() {
+                return Err(format!(
+                    "[{}] needs exactly {} {} rows, found {}",
+                    label,
+                    sizes.len(),
+                    name,
+                    rows.len()
+                ));
+            }
+        }
+    }
+
+    let mut rows: [Vec<Key>; 3] = Default::default();
+    let mut seen = Vec::new();
+
+    for (row, size) in sizes.into_iter().enumerate() {
+        let tokens = RowTokens {
+            keys: &section.rows[row],
+            shifted: section.shifted.as_ref().map(|rows| rows[row].as_str()),
+            altgr: section.altgr.as_ref().map(|rows| rows[row].as_str()),
+            fingers: section.fingers.as_ref().map(|rows| rows[row].as_str()),
+        };
+        rows[row] = parse_row(
+            &format!("[{}] row {}", label, row + 1),
+            tokens,
+            size,
+            |col| form_factor.standard_finger(row, col),
+            &mut seen,
+        )?;
+    }
+
+    let numbers = match &section.numbers {
+        Some(numbers) => {
+            let tokens = RowTokens {
+                keys: numbers,
+                shifted: section.shifted_numbers.as_deref(),
+                altgr: section.altgr_numbers.as_deref(),
+                fingers: section.number_fingers.as_deref(),
+            };
+            parse_row(
+                &format!("[{}] number row", label),
+                tokens,
+                NUMBER_ROW_SIZE,
+                |col| form_factor.standard_finger(NUMBER_ROW, col),
+                &mut seen,
+            )?
+        }
+        None => vec![Key::None; NUMBER_ROW_SIZE],
+    };
+
+    Ok((rows, numbers))
+}
+
+fn parse_row(
+    label: &str,
+    tokens: RowTokens,
+    size: usize,
+    standard_finger: impl Fn(usize) -> Finger,
+    seen: &mut Vec<char>,
+) -> Result<Vec<Key>, String> {
+    fn split(tokens: &str) -> Vec<&str> {
+        tokens.split_whitespace().collect()
+    }
+
+    let keys = split(tokens.keys);
+    let shifted = tokens.shifted.map(split);
+    let altgr = tokens.altgr.map(split);
+    let fingers = tokens.fingers.map(split);
+
+    let layers = [
+        ("keys", Some(&keys)),
+        ("shifted keys", shifted.as_ref()),
+        ("AltGr keys", altgr.as_ref()),
+        ("fingers", fingers.as_ref()),
+    ];
+    for (name, tokens) in layers {
+        if let Some(tokens) = tokens {
+            if tokens.len() != size {
+                return Err(format!(
+                    "{} needs {} {}, found {}",
+                    label,
+                    size,
+                    name,
+                    tokens.len()
+                ));
+            }
+        }
+    }
+
+    let mut row = Vec::with_capacity(size);
+    for (col, token) in keys.into_iter().enumerate() {
+        let position = format!("{} key {}", label, col + 1);
+
+        if token == "_" {
+            row.push(Key::None);
+            continue;
+        }
+
+        let Some(ch) = parse_char(token) else {
+            return Err(format!(
+                "{}: \"{}\" is not a single character",
+                position, token
+            ));
+        };
+        let ch = lowercase(ch);
+
+        // Letters shift to upper case unless the shifted rows say otherwise
+        let shifted = match &shifted {
+            Some(shifted) => parse_layer(shifted[col], &position, "shifted")?,
+            None => Some(uppercase(ch)).filter(|upper| *upper != ch),
+        };
+        let altgr = match &altgr {
+            Some(altgr) => parse_layer(altgr[col], &position, "AltGr")?,
+            None => None,
+        };
+
+        for ch in [Some(ch), shifted, altgr].into_iter().flatten() {
+            if seen.contains(&ch) {
+                return Err(format!("{}: '{}' appears more than once", position, ch));
+            }
+            seen.push(ch);
+        }
+
+        let finger = match &fingers {
+            Some(fingers) => parse_finger(fingers[col]).ok_or_else(|| {
+                format!(
+                    "{}: unknown finger \"{}\", expected one of P, R, M, I or T",
+                    position, fingers[col]
+                )
+            })?,
+            None => standard_finger(col),
+        };
+
+        row.push(Key::Char(ch, shifted, altgr, finger));
+    }
+
+    Ok(row)
+}
+
+fn parse_layer(token: &str, position: &str, layer: &str) -> Result<Option<char>, String> {
+    if token == "_" {
+        return Ok(None);
+    }
+    parse_char(token).map(Some).ok_or_else(|| {
+        format!(
+            "{}: {} \"{}\" is not a single character",
+            position, layer, token
+        )
+    })
+}
+
+// Characters are written as is, or as `U+XXXX` for ones that are hard to
+// type or clash with `_`
+fn parse_char(token: &str) -> Option<char> {
+    if let Some(hex) = token.strip_prefix("U+") {
+        return char::from_u32(u32::from_str_radix(hex, 16).ok()?);
+    }
+
+    let mut chars = token.chars();
+    match (chars.next(), chars.next()) {
+        (Some(ch), None) => Some(ch),
+        _ => None,
+    }
+}
+
+fn parse_finger(code: &str) -> Option<Finger> {
+    match code {
+        "P" | "p" => Some(Finger::Pinky),
+        "R" | "r" => Some(Finger::Ring),
+        "M" | "m" => Some(Finger::Middle),
+        "I" | "i" => Some(Finger::Index),
+        "T" | "t" => Some(Finger::Thumb),
+        _ => None,
+    }
+}
+
+fn to_array<const N: usize>(keys: Vec<Key>) -> [Key; N] {
+    keys.try_into()
+        .unwrap_or_else(|_| panic!("row was validated to contain {} keys", N))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(contents: &str) -> Result<LoadedLayout, String> {
+        parse_layout_file(contents, Path::new("."))
+    }
+
+    #[test]
+    fn parses_iso_section() {
+        let layout = parse(
+            r#"
+            name = "Test"
+
+            [iso]
+            rows = [
+                "q w f p b _ j l u y ; _",
+                "a r s t g _ m n e i o _",
+                "z x c d v _ k h , . /",
+            ]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(layout.name, "Test");
+        assert!(layout.ansi.is_none());
+        let iso = layout.iso.unwrap();
+        assert!(matches!(iso.row2[8], Key::Char(',', .., Finger::Middle)));
+        assert!(matches!(iso.row2[5], Key::None));
+    }
+
+    #[test]
+    fn rejects_wrong_row_length() {
+        let err = parse(
+            r#"
+            name = "Test"
+
+            [iso]
+            rows = [
+                "q w f p b _ j l u y ;",
+                "a r s t g _ m n e i o _",
+                "z x c d v _ k h , . /",
+            ]
+            "#,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(err, "[iso] row 1 needs 12 keys, found 11");
+    }
+
+    #[test]
+    fn rejects_unknown_finger() {
+        let err = parse(
+            r#"
+            name = "Test"
+
+            [ansi]
+            rows = [
+                "q w f p b j l u y ; _ _ _",
+                "a r s t g m n e i o _",
+                "x c d v z k h , . /",
+            ]
+            fingers = [
+                "P R M I I I I M R P P P P",
+                "P R M I I I I M R P P",
+                "P R M X I I I M R P",
+            ]
+            "#,
+        )
+        .err()
+        .unwrap();
+
+        assert_eq!(
+            err,
+            "[ansi] row 3 key 4: unknown finger \"X\", expected one of P, R, M, I or T"
+        );
+    }
+}
@@ -0,0 +1,65 @@
+use crate::keyboard::{Finger, IsoKeyboardLayout, Key};
+
+pub fn iso() -> IsoKeyboardLayout {
+    IsoKeyboardLayout {
+        name: String::from("AZERTY (France)"),
+        numbers: [
+            Key::Char('²', Some('~'), Some('¬'), Finger::Pinky),
+            Key::Char('&', Some('1'), Some('¹'), Finger::Pinky),
+            Key::Char('é', Some('2'), None, Finger::Ring),
+            Key::Char('"', Some('3'), Some('#'), Finger::Middle),
+            Key::Char('\'', Some('4'), Some('{'), Finger::Index),
+            Key::Char('(', Some('5'), Some('['), Finger::Index),
+            Key::Char('-', Some('6'), Some('|'), Finger::Index),
+            Key::Char('è', Some('7'), Some('`'), Finger::Index),
+            Key::Char('_', Some('8'), Some('\\'), Finger::Middle),
+            Key::Char('ç', Some('9'), None, Finger::Ring),
+            Key::Char('à', Some('0'), Some('@'), Finger::Pinky),
+            Key::Char(')', Some('°'), Some(']'), Finger::Pinky),
+            Key::Char('=', Some('+'), Some('}'), Finger::Pinky),
+        ],
+        row0: [
+            Key::Char('a', Some('A'), Some('æ'), Finger::Pinky),
+            Key::Char('z', Some('Z'), Some('«'), Finger::Ring),
+            Key::Char('e', Some('E'), Some('€'), Finger::Middle),
+            Key::Char('r', Some('R'), Some('¶'), Finger::Index),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('u', Some('U'), None, Finger::Index),
+            Key::Char('i', Some('I'), None, Finger::Middle),
+            Key::Char('o', Some('O'), Some('ø'), Finger::Ring),
+            Key::Char('p', Some('P'), Some('þ'), Finger::Pinky),
+            Key::Char('^', Some('¨'), None, Finger::Pinky),
+            Key::Char('$', Some('£'), Some('¤'), Finger::Pinky),
+        ],
+        row1: [
+            Key::Char('q', Some('Q'), None, Finger::Pinky),
+            Key::Char('s', Some('S'), Some('ß'), Finger::Ring),
+            Key::Char('d', Some('D'), Some('ð'), Finger::Middle),
+            Key::Char('f', Some('F'), Some('đ'), Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('h', Some('H'), Some('ħ'), Finger::Index),
+            Key::Char('j', Some('J'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Middle),
+            Key::Char('l', Some('L'), Some('ł'), Finger::Ring),
+            Key::Char('m', Some('M'), None, Finger::Pinky),
+            Key::Char('ù', Some('%'), None, Finger::Pinky),
+            Key::Char('*', Some('µ'), None, Finger::Pinky),
+        ],
+        row2: [
+            Key::Char('<', Some('>'), None, Finger::Pinky),
+            Key::Char('w', Some('W'), None, Finger::Pinky),
+            Key::Char('x', Some('X'), Some('»'), Finger::Ring),
+            Key::Char('c', Some('C'), Some('¢'), Finger::Middle),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('b', Some('B'), Some('“'), Finger::Index),
+            Key::Char('n', Some('N'), Some('”'), Finger::Index),
+            Key::Char(',', Some('?'), Some('´'), Finger::Index),
+            Key::Char(';', Some('.'), Some('•'), Finger::Middle),
+            Key::Char(':', Some('/'), Some('·'), Finger::Ring),
+            Key::Char('!', Some('§'), None, Finger::Pinky),
+        ],
+        dead_keys: vec!['^', '¨', '´'],
+        progression: Vec::new(),
+    }
+}
@@ -2,91 +2,127 @@ use crate::keyboard::{AnsiKeyboardLayout, Finger, IsoKeyboardLayout, Key};
 
 pub fn iso() -> IsoKeyboardLayout {
     IsoKeyboardLayout {
+        name: String::from("Colemak-DH"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), Some('¡'), Finger::Pinky),
+            Key::Char('2', Some('@'), Some('º'), Finger::Ring),
+            Key::Char('3', Some('#'), Some('ª'), Finger::Middle),
+            Key::Char('4', Some('$'), Some('¢'), Finger::Index),
+            Key::Char('5', Some('%'), Some('€'), Finger::Index),
+            Key::Char('6', Some('^'), Some('ħ'), Finger::Index),
+            Key::Char('7', Some('&'), Some('ð'), Finger::Index),
+            Key::Char('8', Some('*'), Some('þ'), Finger::Middle),
+            Key::Char('9', Some('('), Some('‘'), Finger::Ring),
+            Key::Char('0', Some(')'), Some('’'), Finger::Pinky),
+            Key::Char('-', Some('_'), Some('–'), Finger::Pinky),
+            Key::Char('=', Some('+'), Some('×'), Finger::Pinky),
+        ],
         row0: [
-            Key::Char('Q', Finger::Pinky),
-            Key::Char('W', Finger::Ring),
-            Key::Char('F', Finger::Middle),
-            Key::Char('P', Finger::Index),
-            Key::Char('B', Finger::Index),
-            Key::Char('J', Finger::Index),
-            Key::Char('L', Finger::Index),
-            Key::Char('U', Finger::Middle),
-            Key::Char('Y', Finger::Ring),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('q', Some('Q'), Some('ä'), Finger::Pinky),
+            Key::Char('w', Some('W'), Some('å'), Finger::Ring),
+            Key::Char('f', Some('F'), Some('ã'), Finger::Middle),
+            Key::Char('p', Some('P'), Some('ø'), Finger::Index),
+            Key::Char('b', Some('B'), None, Finger::Index),
+            Key::Char('j', Some('J'), Some('đ'), Finger::Index),
+            Key::Char('l', Some('L'), Some('ł'), Finger::Index),
+            Key::Char('u', Some('U'), Some('ú'), Finger::Middle),
+            Key::Char('y', Some('Y'), Some('ü'), Finger::Ring),
+            Key::Char(';', Some(':'), Some('ö'), Finger::Pinky),
+            Key::Char('[', Some('{'), Some('«'), Finger::Pinky),
+            Key::Char(']', Some('}'), Some('»'), Finger::Pinky),
         ],
         row1: [
-            Key::Char('A', Finger::Pinky),
-            Key::Char('R', Finger::Ring),
-            Key::Char('S', Finger::Middle),
-            Key::Char('T', Finger::Index),
-            Key::Char('G', Finger::Index),
-            Key::Char('M', Finger::Index),
-            Key::Char('N', Finger::Index),
-            Key::Char('E', Finger::Middle),
-            Key::Char('I', Finger::Ring),
-            Key::Char('O', Finger::Pinky),
-            Key::None,
-            Key::None,
+            Key::Char('a', Some('A'), Some('á'), Finger::Pinky),
+            Key::Char('r', Some('R'), None, Finger::Ring),
+            Key::Char('s', Some('S'), Some('ß'), Finger::Middle),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('m', Some('M'), None, Finger::Index),
+            Key::Char('n', Some('N'), Some('ñ'), Finger::Index),
+            Key::Char('e', Some('E'), Some('é'), Finger::Middle),
+            Key::Char('i', Some('I'), Some('í'), Finger::Ring),
+            Key::Char('o', Some('O'), Some('ó'), Finger::Pinky),
+            Key::Char('\'', Some('"'), Some('õ'), Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
         ],
         row2: [
-            Key::Char('Z', Finger::Pinky),
-            Key::Char('X', Finger::Ring),
-            Key::Char('C', Finger::Middle),
-            Key::Char('D', Finger::Index),
-            Key::Char('V', Finger::Index),
-            Key::None,
-            Key::Char('K', Finger::Index),
-            Key::Char('H', Finger::Index),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('z', Some('Z'), Some('æ'), Finger::Pinky),
+            Key::Char('x', Some('X'), None, Finger::Ring),
+            Key::Char('c', Some('C'), Some('ç'), Finger::Middle),
+            Key::Char('d', Some('D'), None, Finger::Index),
+            Key::Char('v', Some('V'), Some('œ'), Finger::Index),
+            Key::None,
+            Key::Char('k', Some('K'), None, Finger::Index),
+            Key::Char('h', Some('H'), None, Finger::Index),
+            Key::Char(',', Some('<'), None, Finger::Middle),
+            Key::Char('.', Some('>'), None, Finger::Ring),
+            Key::Char('/', Some('?'), Some('¿'), Finger::Pinky),
         ],
+        dead_keys: Vec::new(),
+        progression: super::progression(&["arstneio", "dh", "wfuy", "plgm", "bcvk", "qjzx"]),
     }
 }
 
 pub fn ansi() -> AnsiKeyboardLayout {
     AnsiKeyboardLayout {
+        name: String::from("Colemak-DH"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), Some('¡'), Finger::Pinky),
+            Key::Char('2', Some('@'), Some('º'), Finger::Ring),
+            Key::Char('3', Some('#'), Some('ª'), Finger::Middle),
+            Key::Char('4', Some('$'), Some('¢'), Finger::Index),
+            Key::Char('5', Some('%'), Some('€'), Finger::Index),
+            Key::Char('6', Some('^'), Some('ħ'), Finger::Index),
+            Key::Char('7', Some('&'), Some('ð'), Finger::Index),
+            Key::Char('8', Some('*'), Some('þ'), Finger::Middle),
+            Key::Char('9', Some('('), Some('‘'), Finger::Ring),
+            Key::Char('0', Some(')'), Some('’'), Finger::Pinky),
+            Key::Char('-', Some('_'), Some('–'), Finger::Pinky),
+            Key::Char('=', Some('+'), Some('×'), Finger::Pinky),
+        ],
         row0: [
-            Key::Char('Q', Finger::Pinky),
-            Key::Char('W', Finger::Ring),
-            Key::Char('F', Finger::Middle),
-            Key::Char('P', Finger::Index),
-            Key::Char('B', Finger::Index),
-            Key::Char('J', Finger::Index),
-            Key::Char('L', Finger::Index),
-            Key::Char('U', Finger::Middle),
-            Key::Char('Y', Finger::Ring),
-            Key::None,
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('q', Some('Q'), Some('ä'), Finger::Pinky),
+            Key::Char('w', Some('W'), Some('å'), Finger::Ring),
+            Key::Char('f', Some('F'), Some('ã'), Finger::Middle),
+            Key::Char('p', Some('P'), Some('ø'), Finger::Index),
+            Key::Char('b', Some('B'), None, Finger::Index),
+            Key::Char('j', Some('J'), Some('đ'), Finger::Index),
+            Key::Char('l', Some('L'), Some('ł'), Finger::Index),
+            Key::Char('u', Some('U'), Some('ú'), Finger::Middle),
+            Key::Char('y', Some('Y'), Some('ü'), Finger::Ring),
+            Key::Char(';', Some(':'), Some('ö'), Finger::Pinky),
+            Key::Char('[', Some('{'), Some('«'), Finger::Pinky),
+            Key::Char(']', Some('}'), Some('»'), Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
         ],
         row1: [
-            Key::Char('A', Finger::Pinky),
-            Key::Char('R', Finger::Ring),
-            Key::Char('S', Finger::Middle),
-            Key::Char('T', Finger::Index),
-            Key::Char('G', Finger::Index),
-            Key::Char('M', Finger::Index),
-            Key::Char('N', Finger::Index),
-            Key::Char('E', Finger::Middle),
-            Key::Char('I', Finger::Ring),
-            Key::Char('O', Finger::Pinky),
-            Key::None,
+            Key::Char('a', Some('A'), Some('á'), Finger::Pinky),
+            Key::Char('r', Some('R'), None, Finger::Ring),
+            Key::Char('s', Some('S'), Some('ß'), Finger::Middle),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('m', Some('M'), None, Finger::Index),
+            Key::Char('n', Some('N'), Some('ñ'), Finger::Index),
+            Key::Char('e', Some('E'), Some('é'), Finger::Middle),
+            Key::Char('i', Some('I'), Some('í'), Finger::Ring),
+            Key::Char('o', Some('O'), Some('ó'), Finger::Pinky),
+            Key::Char('\'', Some('"'), Some('õ'), Finger::Pinky),
         ],
         row2: [
-            Key::Char('Z', Finger::Pinky),
-            Key::Char('X', Finger::Ring),
-            Key::Char('C', Finger::Middle),
-            Key::Char('D', Finger::Index),
-            Key::Char('V', Finger::Index),
-            Key::Char('K', Finger::Index),
-            Key::Char('H', Finger::Index),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('x', Some('X'), None, Finger::Ring),
+            Key::Char('c', Some('C'), Some('ç'), Finger::Middle),
+            Key::Char('d', Some('D'), None, Finger::Index),
+            Key::Char('v', Some('V'), Some('œ'), Finger::Index),
+            Key::Char('z', Some('Z'), Some('æ'), Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Index),
+            Key::Char('h', Some('H'), None, Finger::Index),
+            Key::Char(',', Some('<'), None, Finger::Middle),
+            Key::Char('.', Some('>'), None, Finger::Ring),
+            Key::Char('/', Some('?'), Some('¿'), Finger::Pinky),
         ],
+        dead_keys: Vec::new(),
+        progression: super::progression(&["arstneio", "dh", "wfuy", "plgm", "bcvk", "qjzx"]),
     }
 }
@@ -0,0 +1,128 @@
+use crate::keyboard::{AnsiKeyboardLayout, Finger, IsoKeyboardLayout, Key};
+
+pub fn iso() -> IsoKeyboardLayout {
+    IsoKeyboardLayout {
+        name: String::from("Dvorak"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), None, Finger::Pinky),
+            Key::Char('2', Some('@'), None, Finger::Ring),
+            Key::Char('3', Some('#'), None, Finger::Middle),
+            Key::Char('4', Some('$'), None, Finger::Index),
+            Key::Char('5', Some('%'), None, Finger::Index),
+            Key::Char('6', Some('^'), None, Finger::Index),
+            Key::Char('7', Some('&'), None, Finger::Index),
+            Key::Char('8', Some('*'), None, Finger::Middle),
+            Key::Char('9', Some('('), None, Finger::Ring),
+            Key::Char('0', Some(')'), None, Finger::Pinky),
+            Key::Char('[', Some('{'), None, Finger::Pinky),
+            Key::Char(']', Some('}'), None, Finger::Pinky),
+        ],
+        row0: [
+            Key::Char('\'', Some('"'), None, Finger::Pinky),
+            Key::Char(',', Some('<'), None, Finger::Ring),
+            Key::Char('.', Some('>'), None, Finger::Middle),
+            Key::Char('p', Some('P'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('f', Some('F'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('c', Some('C'), None, Finger::Middle),
+            Key::Char('r', Some('R'), None, Finger::Ring),
+            Key::Char('l', Some('L'), None, Finger::Pinky),
+            Key::Char('/', Some('?'), None, Finger::Pinky),
+            Key::Char('=', Some('+'), None, Finger::Pinky),
+        ],
+        row1: [
+            Key::Char('a', Some('A'), None, Finger::Pinky),
+            Key::Char('o', Some('O'), None, Finger::Ring),
+            Key::Char('e', Some('E'), None, Finger::Middle),
+            Key::Char('u', Some('U'), None, Finger::Index),
+            Key::Char('i', Some('I'), None, Finger::Index),
+            Key::Char('d', Some('D'), None, Finger::Index),
+            Key::Char('h', Some('H'), None, Finger::Index),
+            Key::Char('t', Some('T'), None, Finger::Middle),
+            Key::Char('n', Some('N'), None, Finger::Ring),
+            Key::Char('s', Some('S'), None, Finger::Pinky),
+            Key::Char('-', Some('_'), None, Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
+        ],
+        row2: [
+            Key::None,
+            Key::Char(';', Some(':'), None, Finger::Pinky),
+            Key::Char('q', Some('Q'), None, Finger::Ring),
+            Key::Char('j', Some('J'), None, Finger::Middle),
+            Key::Char('k', Some('K'), None, Finger::Index),
+            Key::Char('x', Some('X'), None, Finger::Index),
+            Key::Char('b', Some('B'), None, Finger::Index),
+            Key::Char('m', Some('M'), None, Finger::Index),
+            Key::Char('w', Some('W'), None, Finger::Middle),
+            Key::Char('v', Some('V'), None, Finger::Ring),
+            Key::Char('z', Some('Z'), None, Finger::Pinky),
+        ],
+        dead_keys: Vec::new(),
+        progression: super::progression(&["aoeuhtns", "id", "crlp", "gfym", "wbvk", "qjzx"]),
+    }
+}
+
+pub fn ansi() -> AnsiKeyboardLayout {
+    AnsiKeyboardLayout {
+        name: String::from("Dvorak"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), None, Finger::Pinky),
+            Key::Char('2', Some('@'), None, Finger::Ring),
+            Key::Char('3', Some('#'), None, Finger::Middle),
+            Key::Char('4', Some('$'), None, Finger::Index),
+            Key::Char('5', Some('%'), None, Finger::Index),
+            Key::Char('6', Some('^'), None, Finger::Index),
+            Key::Char('7', Some('&'), None, Finger::Index),
+            Key::Char('8', Some('*'), None, Finger::Middle),
+            Key::Char('9', Some('('), None, Finger::Ring),
+            Key::Char('0', Some(')'), None, Finger::Pinky),
+            Key::Char('[', Some('{'), None, Finger::Pinky),
+            Key::Char(']', Some('}'), None, Finger::Pinky),
+        ],
+        row0: [
+            Key::Char('\'', Some('"'), None, Finger::Pinky),
+            Key::Char(',', Some('<'), None, Finger::Ring),
+            Key::Char('.', Some('>'), None, Finger::Middle),
+            Key::Char('p', Some('P'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('f', Some('F'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('c', Some('C'), None, Finger::Middle),
+            Key::Char('r', Some('R'), None, Finger::Ring),
+            Key::Char('l', Some('L'), None, Finger::Pinky),
+            Key::Char('/', Some('?'), None, Finger::Pinky),
+            Key::Char('=', Some('+'), None, Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
+        ],
+        row1: [
+            Key::Char('a', Some('A'), None, Finger::Pinky),
+            Key::Char('o', Some('O'), None, Finger::Ring),
+            Key::Char('e', Some('E'), None, Finger::Middle),
+            Key::Char('u', Some('U'), None, Finger::Index),
+            Key::Char('i', Some('I'), None, Finger::Index),
+            Key::Char('d', Some('D'), None, Finger::Index),
+            Key::Char('h', Some('H'), None, Finger::Index),
+            Key::Char('t', Some('T'), None, Finger::Middle),
+            Key::Char('n', Some('N'), None, Finger::Ring),
+            Key::Char('s', Some('S'), None, Finger::Pinky),
+            Key::Char('-', Some('_'), None, Finger::Pinky),
+        ],
+        row2: [
+            Key::Char(';', Some(':'), None, Finger::Pinky),
+            Key::Char('q', Some('Q'), None, Finger::Ring),
+            Key::Char('j', Some('J'), None, Finger::Middle),
+            Key::Char('k', Some('K'), None, Finger::Index),
+            Key::Char('x', Some('X'), None, Finger::Index),
+            Key::Char('b', Some('B'), None, Finger::Index),
+            Key::Char('m', Some('M'), None, Finger::Index),
+            Key::Char('w', Some('W'), None, Finger::Middle),
+            Key::Char('v', Some('V'), None, Finger::Ring),
+            Key::Char('z', Some('Z'), None, Finger::Pinky),
+        ],
+        dead_keys: Vec::new(),
+        progression: super::progression(&["aoeuhtns", "id", "crlp", "gfym", "wbvk", "qjzx"]),
+    }
+}
@@ -0,0 +1,128 @@
+use crate::keyboard::{AnsiKeyboardLayout, Finger, IsoKeyboardLayout, Key};
+
+pub fn iso() -> IsoKeyboardLayout {
+    IsoKeyboardLayout {
+        name: String::from("Graphite"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), None, Finger::Pinky),
+            Key::Char('2', Some('@'), None, Finger::Ring),
+            Key::Char('3', Some('#'), None, Finger::Middle),
+            Key::Char('4', Some('$'), None, Finger::Index),
+            Key::Char('5', Some('%'), None, Finger::Index),
+            Key::Char('6', Some('^'), None, Finger::Index),
+            Key::Char('7', Some('&'), None, Finger::Index),
+            Key::Char('8', Some('*'), None, Finger::Middle),
+            Key::Char('9', Some('('), None, Finger::Ring),
+            Key::Char('0', Some(')'), None, Finger::Pinky),
+            Key::Char('[', Some('{'), None, Finger::Pinky),
+            Key::Char(']', Some('}'), None, Finger::Pinky),
+        ],
+        row0: [
+            Key::Char('b', Some('B'), None, Finger::Pinky),
+            Key::Char('l', Some('L'), None, Finger::Ring),
+            Key::Char('d', Some('D'), None, Finger::Middle),
+            Key::Char('w', Some('W'), None, Finger::Index),
+            Key::Char('z', Some('Z'), None, Finger::Index),
+            Key::Char('\'', Some('_'), None, Finger::Index),
+            Key::Char('f', Some('F'), None, Finger::Index),
+            Key::Char('o', Some('O'), None, Finger::Middle),
+            Key::Char('u', Some('U'), None, Finger::Ring),
+            Key::Char('j', Some('J'), None, Finger::Pinky),
+            Key::Char(';', Some(':'), None, Finger::Pinky),
+            Key::Char('=', Some('+'), None, Finger::Pinky),
+        ],
+        row1: [
+            Key::Char('n', Some('N'), None, Finger::Pinky),
+            Key::Char('r', Some('R'), None, Finger::Ring),
+            Key::Char('t', Some('T'), None, Finger::Middle),
+            Key::Char('s', Some('S'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('h', Some('H'), None, Finger::Index),
+            Key::Char('a', Some('A'), None, Finger::Middle),
+            Key::Char('e', Some('E'), None, Finger::Ring),
+            Key::Char('i', Some('I'), None, Finger::Pinky),
+            Key::Char(',', Some('?'), None, Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
+        ],
+        row2: [
+            Key::None,
+            Key::Char('q', Some('Q'), None, Finger::Pinky),
+            Key::Char('x', Some('X'), None, Finger::Ring),
+            Key::Char('m', Some('M'), None, Finger::Middle),
+            Key::Char('c', Some('C'), None, Finger::Index),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Index),
+            Key::Char('p', Some('P'), None, Finger::Index),
+            Key::Char('.', Some('>'), None, Finger::Middle),
+            Key::Char('-', Some('"'), None, Finger::Ring),
+            Key::Char('/', Some('<'), None, Finger::Pinky),
+        ],
+        dead_keys: Vec::new(),
+        progression: super::progression(&["nrtshaei", "od", "lfuw", "gycm", "pbvk", "qjzx"]),
+    }
+}
+
+pub fn ansi() -> AnsiKeyboardLayout {
+    AnsiKeyboardLayout {
+        name: String::from("Graphite"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), None, Finger::Pinky),
+            Key::Char('2', Some('@'), None, Finger::Ring),
+            Key::Char('3', Some('#'), None, Finger::Middle),
+            Key::Char('4', Some('$'), None, Finger::Index),
+            Key::Char('5', Some('%'), None, Finger::Index),
+            Key::Char('6', Some('^'), None, Finger::Index),
+            Key::Char('7', Some('&'), None, Finger::Index),
+            Key::Char('8', Some('*'), None, Finger::Middle),
+            Key::Char('9', Some('('), None, Finger::Ring),
+            Key::Char('0', Some(')'), None, Finger::Pinky),
+            Key::Char('[', Some('{'), None, Finger::Pinky),
+            Key::Char(']', Some('}'), None, Finger::Pinky),
+        ],
+        row0: [
+            Key::Char('b', Some('B'), None, Finger::Pinky),
+            Key::Char('l', Some('L'), None, Finger::Ring),
+            Key::Char('d', Some('D'), None, Finger::Middle),
+            Key::Char('w', Some('W'), None, Finger::Index),
+            Key::Char('z', Some('Z'), None, Finger::Index),
+            Key::Char('\'', Some('_'), None, Finger::Index),
+            Key::Char('f', Some('F'), None, Finger::Index),
+            Key::Char('o', Some('O'), None, Finger::Middle),
+            Key::Char('u', Some('U'), None, Finger::Ring),
+            Key::Char('j', Some('J'), None, Finger::Pinky),
+            Key::Char(';', Some(':'), None, Finger::Pinky),
+            Key::Char('=', Some('+'), None, Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
+        ],
+        row1: [
+            Key::Char('n', Some('N'), None, Finger::Pinky),
+            Key::Char('r', Some('R'), None, Finger::Ring),
+            Key::Char('t', Some('T'), None, Finger::Middle),
+            Key::Char('s', Some('S'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('h', Some('H'), None, Finger::Index),
+            Key::Char('a', Some('A'), None, Finger::Middle),
+            Key::Char('e', Some('E'), None, Finger::Ring),
+            Key::Char('i', Some('I'), None, Finger::Pinky),
+            Key::Char(',', Some('?'), None, Finger::Pinky),
+        ],
+        row2: [
+            Key::Char('q', Some('Q'), None, Finger::Pinky),
+            Key::Char('x', Some('X'), None, Finger::Ring),
+            Key::Char('m', Some('M'), None, Finger::Middle),
+            Key::Char('c', Some('C'), None, Finger::Index),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Index),
+            Key::Char('p', Some('P'), None, Finger::Index),
+            Key::Char('.', Some('>'), None, Finger::Middle),
+            Key::Char('-', Some('"'), None, Finger::Ring),
+            Key::Char('/', Some('<'), None, Finger::Pinky),
+        ],
+        dead_keys: Vec::new(),
+        progression: super::progression(&["nrtshaei", "od", "lfuw", "gycm", "pbvk", "qjzx"]),
+    }
+}
@@ -0,0 +1,65 @@
+use crate::keyboard::{Finger, IsoKeyboardLayout, Key};
+
+pub fn iso() -> IsoKeyboardLayout {
+    IsoKeyboardLayout {
+        name: String::from("Italian"),
+        numbers: [
+            Key::Char('\\', Some('|'), Some('¬'), Finger::Pinky),
+            Key::Char('1', Some('!'), Some('¹'), Finger::Pinky),
+            Key::Char('2', Some('"'), Some('²'), Finger::Ring),
+            Key::Char('3', Some('£'), Some('³'), Finger::Middle),
+            Key::Char('4', Some('$'), Some('¼'), Finger::Index),
+            Key::Char('5', Some('%'), Some('½'), Finger::Index),
+            Key::Char('6', Some('&'), None, Finger::Index),
+            Key::Char('7', Some('/'), Some('{'), Finger::Index),
+            Key::Char('8', Some('('), Some('['), Finger::Middle),
+            Key::Char('9', Some(')'), Some(']'), Finger::Ring),
+            Key::Char('0', Some('='), Some('}'), Finger::Pinky),
+            Key::Char('\'', Some('?'), Some('`'), Finger::Pinky),
+            Key::Char('ì', Some('^'), Some('~'), Finger::Pinky),
+        ],
+        row0: [
+            Key::Char('q', Some('Q'), Some('@'), Finger::Pinky),
+            Key::Char('w', Some('W'), Some('ſ'), Finger::Ring),
+            Key::Char('e', Some('E'), Some('€'), Finger::Middle),
+            Key::Char('r', Some('R'), Some('¶'), Finger::Index),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('u', Some('U'), None, Finger::Index),
+            Key::Char('i', Some('I'), None, Finger::Middle),
+            Key::Char('o', Some('O'), Some('ø'), Finger::Ring),
+            Key::Char('p', Some('P'), Some('þ'), Finger::Pinky),
+            Key::Char('è', Some('é'), None, Finger::Pinky),
+            Key::Char('+', Some('*'), None, Finger::Pinky),
+        ],
+        row1: [
+            Key::Char('a', Some('A'), Some('æ'), Finger::Pinky),
+            Key::Char('s', Some('S'), Some('ß'), Finger::Ring),
+            Key::Char('d', Some('D'), Some('ð'), Finger::Middle),
+            Key::Char('f', Some('F'), Some('đ'), Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('h', Some('H'), Some('ħ'), Finger::Index),
+            Key::Char('j', Some('J'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Middle),
+            Key::Char('l', Some('L'), Some('ł'), Finger::Ring),
+            Key::Char('ò', Some('ç'), None, Finger::Pinky),
+            Key::Char('à', Some('°'), Some('#'), Finger::Pinky),
+            Key::Char('ù', Some('§'), None, Finger::Pinky),
+        ],
+        row2: [
+            Key::Char('<', Some('>'), Some('«'), Finger::Pinky),
+            Key::Char('z', Some('Z'), None, Finger::Pinky),
+            Key::Char('x', Some('X'), Some('»'), Finger::Ring),
+            Key::Char('c', Some('C'), Some('¢'), Finger::Middle),
+            Key::Char('v', Some('V'), Some('“'), Finger::Index),
+            Key::Char('b', Some('B'), Some('”'), Finger::Index),
+            Key::Char('n', Some('N'), Some('ñ'), Finger::Index),
+            Key::Char('m', Some('M'), Some('µ'), Finger::Index),
+            Key::Char(',', Some(';'), Some('´'), Finger::Middle),
+            Key::Char('.', Some(':'), Some('·'), Finger::Ring),
+            Key::Char('-', Some('_'), Some('¯'), Finger::Pinky),
+        ],
+        dead_keys: vec!['¯', '´'],
+        progression: Vec::new(),
+    }
+}
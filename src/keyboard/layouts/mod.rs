@@ -1,3 +1,112 @@
+use std::{path::Path, sync::OnceLock};
+
+use super::{AnsiKeyboardLayout, IsoKeyboardLayout, LayoutFileError};
+
+pub mod azerty_be;
+pub mod azerty_fr;
+pub mod canary;
+pub mod colemak;
 pub mod colemak_dh;
+pub mod colemak_dh_matrix;
+pub mod danish;
+pub mod dvorak;
+pub mod graphite;
+pub mod italian;
+pub mod norwegian;
 pub mod qwerty;
 pub mod qwertz;
+pub mod spanish;
+pub mod swedish;
+pub mod workman;
+
+static CUSTOM: OnceLock<CustomLayouts> = OnceLock::new();
+
+#[derive(Default)]
+struct CustomLayouts {
+    iso: Vec<IsoKeyboardLayout>,
+    ansi: Vec<AnsiKeyboardLayout>,
+}
+
+pub fn all_iso() -> Vec<IsoKeyboardLayout> {
+    let mut layouts = vec![
+        qwerty::iso(),
+        qwertz::iso(),
+        azerty_fr::iso(),
+        azerty_be::iso(),
+        swedish::iso(),
+        norwegian::iso(),
+        danish::iso(),
+        spanish::iso(),
+        italian::iso(),
+        colemak::iso(),
+        colemak_dh::iso(),
+        colemak_dh_matrix::iso(),
+        workman::iso(),
+        dvorak::iso(),
+        canary::iso(),
+        graphite::iso(),
+    ];
+    if let Some(custom) = CUSTOM.get() {
+        layouts.extend(custom.iso.iter().cloned());
+    }
+    layouts
+}
+
+pub fn all_ansi() -> Vec<AnsiKeyboardLayout> {
+    let mut layouts = vec![
+        qwerty::ansi(),
+        qwertz::ansi(),
+        colemak::ansi(),
+        colemak_dh::ansi(),
+        colemak_dh_matrix::ansi(),
+        workman::ansi(),
+        dvorak::ansi(),
+        canary::ansi(),
+        graphite::ansi(),
+    ];
+    if let Some(custom) = CUSTOM.get() {
+        layouts.extend(custom.ansi.iter().cloned());
+    }
+    layouts
+}
+
+// The letters each level unlocks, starting with level one
+pub fn progression(levels: &[&str]) -> Vec<String> {
+    levels.iter().map(|letters| letters.to_string()).collect()
+}
+
+pub fn iso(name: &str) -> Option<IsoKeyboardLayout> {
+    all_iso().into_iter().find(|layout| layout.name == name)
+}
+
+pub fn ansi(name: &str) -> Option<AnsiKeyboardLayout> {
+    all_ansi().into_iter().find(|layout| layout.name == name)
+}
+
+pub fn load_custom(dir: &Path) -> Vec<LayoutFileError> {
+    let (layouts, mut errors) = super::load_layout_files(dir);
+
+    let mut custom = CustomLayouts::default();
+    let builtin_iso = all_iso();
+    let builtin_ansi = all_ansi();
+
+    for (path, file) in layouts {
+        if builtin_iso.iter().any(|l| l.name == file.name)
+            || builtin_ansi.iter().any(|l| l.name == file.name)
+            || custom.iso.iter().any(|l| l.name == file.name)
+            || custom.ansi.iter().any(|l| l.name == file.name)
+        {
+            errors.push(LayoutFileError::new(
+                &path,
+                format!("a layout named \"{}\" already exists", file.name),
+            ));
+            continue;
+        }
+
+        custom.iso.extend(file.iso);
+        custom.ansi.extend(file.ansi);
+    }
+
+    let _ = CUSTOM.set(custom);
+    errors
+}
@@ -2,91 +2,127 @@ use crate::keyboard::{AnsiKeyboardLayout, Finger, IsoKeyboardLayout, Key};
 
 pub fn iso() -> IsoKeyboardLayout {
     IsoKeyboardLayout {
+        name: String::from("QWERTY"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), None, Finger::Pinky),
+            Key::Char('2', Some('@'), None, Finger::Ring),
+            Key::Char('3', Some('#'), None, Finger::Middle),
+            Key::Char('4', Some('$'), None, Finger::Index),
+            Key::Char('5', Some('%'), None, Finger::Index),
+            Key::Char('6', Some('^'), None, Finger::Index),
+            Key::Char('7', Some('&'), None, Finger::Index),
+            Key::Char('8', Some('*'), None, Finger::Middle),
+            Key::Char('9', Some('('), None, Finger::Ring),
+            Key::Char('0', Some(')'), None, Finger::Pinky),
+            Key::Char('-', Some('_'), None, Finger::Pinky),
+            Key::Char('=', Some('+'), None, Finger::Pinky),
+        ],
         row0: [
-            Key::Char('Q', Finger::Pinky),
-            Key::Char('W', Finger::Ring),
-            Key::Char('E', Finger::Middle),
-            Key::Char('R', Finger::Index),
-            Key::Char('T', Finger::Index),
-            Key::Char('Y', Finger::Index),
-            Key::Char('U', Finger::Index),
-            Key::Char('I', Finger::Middle),
-            Key::Char('O', Finger::Ring),
-            Key::Char('P', Finger::Pinky),
-            Key::None,
-            Key::None,
+            Key::Char('q', Some('Q'), None, Finger::Pinky),
+            Key::Char('w', Some('W'), None, Finger::Ring),
+            Key::Char('e', Some('E'), None, Finger::Middle),
+            Key::Char('r', Some('R'), None, Finger::Index),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('u', Some('U'), None, Finger::Index),
+            Key::Char('i', Some('I'), None, Finger::Middle),
+            Key::Char('o', Some('O'), None, Finger::Ring),
+            Key::Char('p', Some('P'), None, Finger::Pinky),
+            Key::Char('[', Some('{'), None, Finger::Pinky),
+            Key::Char(']', Some('}'), None, Finger::Pinky),
         ],
         row1: [
-            Key::Char('A', Finger::Pinky),
-            Key::Char('S', Finger::Ring),
-            Key::Char('D', Finger::Middle),
-            Key::Char('F', Finger::Index),
-            Key::Char('G', Finger::Index),
-            Key::Char('H', Finger::Index),
-            Key::Char('J', Finger::Index),
-            Key::Char('K', Finger::Middle),
-            Key::Char('L', Finger::Ring),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('a', Some('A'), None, Finger::Pinky),
+            Key::Char('s', Some('S'), None, Finger::Ring),
+            Key::Char('d', Some('D'), None, Finger::Middle),
+            Key::Char('f', Some('F'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('h', Some('H'), None, Finger::Index),
+            Key::Char('j', Some('J'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Middle),
+            Key::Char('l', Some('L'), None, Finger::Ring),
+            Key::Char(';', Some(':'), None, Finger::Pinky),
+            Key::Char('\'', Some('"'), None, Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
         ],
         row2: [
-            Key::None,
-            Key::Char('Z', Finger::Pinky),
-            Key::Char('X', Finger::Ring),
-            Key::Char('C', Finger::Middle),
-            Key::Char('V', Finger::Index),
-            Key::Char('B', Finger::Index),
-            Key::Char('N', Finger::Index),
-            Key::Char('M', Finger::Index),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('<', None, None, Finger::Pinky),
+            Key::Char('z', Some('Z'), None, Finger::Pinky),
+            Key::Char('x', Some('X'), None, Finger::Ring),
+            Key::Char('c', Some('C'), None, Finger::Middle),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('b', Some('B'), None, Finger::Index),
+            Key::Char('n', Some('N'), None, Finger::Index),
+            Key::Char('m', Some('M'), None, Finger::Index),
+            Key::Char(',', Some('<'), None, Finger::Middle),
+            Key::Char('.', Some('>'), None, Finger::Ring),
+            Key::Char('/', Some('?'), None, Finger::Pinky),
         ],
+        dead_keys: Vec::new(),
+        progression: Vec::new(),
     }
 }
 
 pub fn ansi() -> AnsiKeyboardLayout {
     AnsiKeyboardLayout {
+        name: String::from("QWERTY"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), None, Finger::Pinky),
+            Key::Char('2', Some('@'), None, Finger::Ring),
+            Key::Char('3', Some('#'), None, Finger::Middle),
+            Key::Char('4', Some('$'), None, Finger::Index),
+            Key::Char('5', Some('%'), None, Finger::Index),
+            Key::Char('6', Some('^'), None, Finger::Index),
+            Key::Char('7', Some('&'), None, Finger::Index),
+            Key::Char('8', Some('*'), None, Finger::Middle),
+            Key::Char('9', Some('('), None, Finger::Ring),
+            Key::Char('0', Some(')'), None, Finger::Pinky),
+            Key::Char('-', Some('_'), None, Finger::Pinky),
+            Key::Char('=', Some('+'), None, Finger::Pinky),
+        ],
         row0: [
-            Key::Char('Q', Finger::Pinky),
-            Key::Char('W', Finger::Ring),
-            Key::Char('E', Finger::Middle),
-            Key::Char('R', Finger::Index),
-            Key::Char('T', Finger::Index),
-            Key::Char('Y', Finger::Index),
-            Key::Char('U', Finger::Index),
-            Key::Char('I', Finger::Middle),
-            Key::Char('O', Finger::Ring),
-            Key::Char('P', Finger::Pinky),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('q', Some('Q'), None, Finger::Pinky),
+            Key::Char('w', Some('W'), None, Finger::Ring),
+            Key::Char('e', Some('E'), None, Finger::Middle),
+            Key::Char('r', Some('R'), None, Finger::Index),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('u', Some('U'), None, Finger::Index),
+            Key::Char('i', Some('I'), None, Finger::Middle),
+            Key::Char('o', Some('O'), None, Finger::Ring),
+            Key::Char('p', Some('P'), None, Finger::Pinky),
+            Key::Char('[', Some('{'), None, Finger::Pinky),
+            Key::Char(']', Some('}'), None, Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
         ],
         row1: [
-            Key::Char('A', Finger::Pinky),
-            Key::Char('S', Finger::Ring),
-            Key::Char('D', Finger::Middle),
-            Key::Char('F', Finger::Index),
-            Key::Char('G', Finger::Index),
-            Key::Char('H', Finger::Index),
-            Key::Char('J', Finger::Index),
-            Key::Char('K', Finger::Middle),
-            Key::Char('L', Finger::Ring),
-            Key::None,
-            Key::None,
+            Key::Char('a', Some('A'), None, Finger::Pinky),
+            Key::Char('s', Some('S'), None, Finger::Ring),
+            Key::Char('d', Some('D'), None, Finger::Middle),
+            Key::Char('f', Some('F'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('h', Some('H'), None, Finger::Index),
+            Key::Char('j', Some('J'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Middle),
+            Key::Char('l', Some('L'), None, Finger::Ring),
+            Key::Char(';', Some(':'), None, Finger::Pinky),
+            Key::Char('\'', Some('"'), None, Finger::Pinky),
         ],
         row2: [
-            Key::Char('Z', Finger::Pinky),
-            Key::Char('X', Finger::Ring),
-            Key::Char('C', Finger::Middle),
-            Key::Char('V', Finger::Index),
-            Key::Char('B', Finger::Index),
-            Key::Char('N', Finger::Index),
-            Key::Char('M', Finger::Index),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('z', Some('Z'), None, Finger::Pinky),
+            Key::Char('x', Some('X'), None, Finger::Ring),
+            Key::Char('c', Some('C'), None, Finger::Middle),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('b', Some('B'), None, Finger::Index),
+            Key::Char('n', Some('N'), None, Finger::Index),
+            Key::Char('m', Some('M'), None, Finger::Index),
+            Key::Char(',', Some('<'), None, Finger::Middle),
+            Key::Char('.', Some('>'), None, Finger::Ring),
+            Key::Char('/', Some('?'), None, Finger::Pinky),
         ],
+        dead_keys: Vec::new(),
+        progression: Vec::new(),
     }
 }
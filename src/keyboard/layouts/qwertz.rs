@@ -2,91 +2,127 @@ use crate::keyboard::{AnsiKeyboardLayout, Finger, IsoKeyboardLayout, Key};
 
 pub fn iso() -> IsoKeyboardLayout {
     IsoKeyboardLayout {
+        name: String::from("QWERTZ"),
+        numbers: [
+            Key::Char('^', Some('°'), Some('′'), Finger::Pinky),
+            Key::Char('1', Some('!'), Some('¹'), Finger::Pinky),
+            Key::Char('2', Some('"'), Some('²'), Finger::Ring),
+            Key::Char('3', Some('§'), Some('³'), Finger::Middle),
+            Key::Char('4', Some('$'), Some('¼'), Finger::Index),
+            Key::Char('5', Some('%'), Some('½'), Finger::Index),
+            Key::Char('6', Some('&'), Some('¬'), Finger::Index),
+            Key::Char('7', Some('/'), Some('{'), Finger::Index),
+            Key::Char('8', Some('('), Some('['), Finger::Middle),
+            Key::Char('9', Some(')'), Some(']'), Finger::Ring),
+            Key::Char('0', Some('='), Some('}'), Finger::Pinky),
+            Key::Char('ß', Some('?'), Some('\\'), Finger::Pinky),
+            Key::Char('´', Some('`'), Some('¸'), Finger::Pinky),
+        ],
         row0: [
-            Key::Char('Q', Finger::Pinky),
-            Key::Char('W', Finger::Ring),
-            Key::Char('E', Finger::Middle),
-            Key::Char('R', Finger::Index),
-            Key::Char('T', Finger::Index),
-            Key::Char('Z', Finger::Index),
-            Key::Char('U', Finger::Index),
-            Key::Char('I', Finger::Middle),
-            Key::Char('O', Finger::Ring),
-            Key::Char('P', Finger::Pinky),
-            Key::None,
-            Key::None,
+            Key::Char('q', Some('Q'), Some('@'), Finger::Pinky),
+            Key::Char('w', Some('W'), Some('ſ'), Finger::Ring),
+            Key::Char('e', Some('E'), Some('€'), Finger::Middle),
+            Key::Char('r', Some('R'), Some('¶'), Finger::Index),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('z', Some('Z'), None, Finger::Index),
+            Key::Char('u', Some('U'), None, Finger::Index),
+            Key::Char('i', Some('I'), None, Finger::Middle),
+            Key::Char('o', Some('O'), Some('ø'), Finger::Ring),
+            Key::Char('p', Some('P'), Some('þ'), Finger::Pinky),
+            Key::Char('ü', Some('Ü'), Some('¨'), Finger::Pinky),
+            Key::Char('+', Some('*'), Some('~'), Finger::Pinky),
         ],
         row1: [
-            Key::Char('A', Finger::Pinky),
-            Key::Char('S', Finger::Ring),
-            Key::Char('D', Finger::Middle),
-            Key::Char('F', Finger::Index),
-            Key::Char('G', Finger::Index),
-            Key::Char('H', Finger::Index),
-            Key::Char('J', Finger::Index),
-            Key::Char('K', Finger::Middle),
-            Key::Char('L', Finger::Ring),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('a', Some('A'), Some('æ'), Finger::Pinky),
+            Key::Char('s', Some('S'), None, Finger::Ring),
+            Key::Char('d', Some('D'), Some('ð'), Finger::Middle),
+            Key::Char('f', Some('F'), Some('đ'), Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('h', Some('H'), Some('ħ'), Finger::Index),
+            Key::Char('j', Some('J'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Middle),
+            Key::Char('l', Some('L'), Some('ł'), Finger::Ring),
+            Key::Char('ö', Some('Ö'), Some('˝'), Finger::Pinky),
+            Key::Char('ä', Some('Ä'), None, Finger::Pinky),
+            Key::Char('#', Some('\''), Some('’'), Finger::Pinky),
         ],
         row2: [
-            Key::None,
-            Key::Char('Y', Finger::Pinky),
-            Key::Char('X', Finger::Ring),
-            Key::Char('C', Finger::Middle),
-            Key::Char('V', Finger::Index),
-            Key::Char('B', Finger::Index),
-            Key::Char('N', Finger::Index),
-            Key::Char('M', Finger::Index),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('<', Some('>'), Some('|'), Finger::Pinky),
+            Key::Char('y', Some('Y'), Some('»'), Finger::Pinky),
+            Key::Char('x', Some('X'), Some('«'), Finger::Ring),
+            Key::Char('c', Some('C'), Some('¢'), Finger::Middle),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('b', Some('B'), Some('“'), Finger::Index),
+            Key::Char('n', Some('N'), Some('”'), Finger::Index),
+            Key::Char('m', Some('M'), Some('µ'), Finger::Index),
+            Key::Char(',', Some(';'), None, Finger::Middle),
+            Key::Char('.', Some(':'), Some('…'), Finger::Ring),
+            Key::Char('-', Some('_'), Some('–'), Finger::Pinky),
         ],
+        dead_keys: vec!['^', '`', '¨', '´', '¸', '˝'],
+        progression: Vec::new(),
     }
 }
 
 pub fn ansi() -> AnsiKeyboardLayout {
     AnsiKeyboardLayout {
-        row0: [
-            Key::Char('Q', Finger::Pinky),
-            Key::Char('W', Finger::Ring),
-            Key::Char('E', Finger::Middle),
-            Key::Char('R', Finger::Index),
-            Key::Char('T', Finger::Index),
-            Key::Char('Z', Finger::Index),
-            Key::Char('U', Finger::Index),
-            Key::Char('I', Finger::Middle),
-            Key::Char('O', Finger::Ring),
-            Key::Char('P', Finger::Pinky),
-            Key::None,
-            Key::None,
+        name: String::from("QWERTZ"),
+        numbers: [
+            Key::None,
+            Key::Char('1', Some('!'), Some('¹'), Finger::Pinky),
+            Key::Char('2', Some('"'), Some('²'), Finger::Ring),
+            Key::Char('3', Some('§'), Some('³'), Finger::Middle),
+            Key::Char('4', Some('$'), Some('¼'), Finger::Index),
+            Key::Char('5', Some('%'), Some('½'), Finger::Index),
+            Key::Char('6', Some('&'), Some('¬'), Finger::Index),
+            Key::Char('7', Some('/'), Some('{'), Finger::Index),
+            Key::Char('8', Some('('), Some('['), Finger::Middle),
+            Key::Char('9', Some(')'), Some(']'), Finger::Ring),
+            Key::Char('0', Some('='), Some('}'), Finger::Pinky),
+            Key::Char('ß', Some('?'), Some('\\'), Finger::Pinky),
             Key::None,
         ],
+        row0: [
+            Key::Char('q', Some('Q'), Some('@'), Finger::Pinky),
+            Key::Char('w', Some('W'), Some('ſ'), Finger::Ring),
+            Key::Char('e', Some('E'), Some('€'), Finger::Middle),
+            Key::Char('r', Some('R'), Some('¶'), Finger::Index),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('z', Some('Z'), None, Finger::Index),
+            Key::Char('u', Some('U'), None, Finger::Index),
+            Key::Char('i', Some('I'), None, Finger::Middle),
+            Key::Char('o', Some('O'), Some('ø'), Finger::Ring),
+            Key::Char('p', Some('P'), Some('þ'), Finger::Pinky),
+            Key::Char('ü', Some('Ü'), None, Finger::Pinky),
+            Key::Char('+', Some('*'), Some('~'), Finger::Pinky),
+            Key::Char('#', Some('\''), Some('’'), Finger::Pinky),
+        ],
         row1: [
-            Key::Char('A', Finger::Pinky),
-            Key::Char('S', Finger::Ring),
-            Key::Char('D', Finger::Middle),
-            Key::Char('F', Finger::Index),
-            Key::Char('G', Finger::Index),
-            Key::Char('H', Finger::Index),
-            Key::Char('J', Finger::Index),
-            Key::Char('K', Finger::Middle),
-            Key::Char('L', Finger::Ring),
-            Key::None,
-            Key::None,
+            Key::Char('a', Some('A'), Some('æ'), Finger::Pinky),
+            Key::Char('s', Some('S'), None, Finger::Ring),
+            Key::Char('d', Some('D'), Some('ð'), Finger::Middle),
+            Key::Char('f', Some('F'), Some('đ'), Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('h', Some('H'), Some('ħ'), Finger::Index),
+            Key::Char('j', Some('J'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Middle),
+            Key::Char('l', Some('L'), Some('ł'), Finger::Ring),
+            Key::Char('ö', Some('Ö'), None, Finger::Pinky),
+            Key::Char('ä', Some('Ä'), None, Finger::Pinky),
         ],
         row2: [
-            Key::Char('Y', Finger::Pinky),
-            Key::Char('X', Finger::Ring),
-            Key::Char('C', Finger::Middle),
-            Key::Char('V', Finger::Index),
-            Key::Char('B', Finger::Index),
-            Key::Char('N', Finger::Index),
-            Key::Char('M', Finger::Index),
-            Key::None,
-            Key::None,
-            Key::None,
+            Key::Char('y', Some('Y'), Some('»'), Finger::Pinky),
+            Key::Char('x', Some('X'), Some('«'), Finger::Ring),
+            Key::Char('c', Some('C'), Some('¢'), Finger::Middle),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('b', Some('B'), Some('“'), Finger::Index),
+            Key::Char('n', Some('N'), Some('”'), Finger::Index),
+            Key::Char('m', Some('M'), Some('µ'), Finger::Index),
+            Key::Char(',', Some(';'), None, Finger::Middle),
+            Key::Char('.', Some(':'), Some('…'), Finger::Ring),
+            Key::Char('-', Some('_'), Some('–'), Finger::Pinky),
         ],
+        dead_keys: Vec::new(),
+        progression: Vec::new(),
     }
 }
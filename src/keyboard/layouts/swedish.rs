@@ -0,0 +1,65 @@
+use crate::keyboard::{Finger, IsoKeyboardLayout, Key};
+
+pub fn iso() -> IsoKeyboardLayout {
+    IsoKeyboardLayout {
+        name: String::from("Swedish/Finnish"),
+        numbers: [
+            Key::Char('§', Some('½'), Some('¶'), Finger::Pinky),
+            Key::Char('1', Some('!'), Some('¡'), Finger::Pinky),
+            Key::Char('2', Some('"'), Some('@'), Finger::Ring),
+            Key::Char('3', Some('#'), Some('£'), Finger::Middle),
+            Key::Char('4', Some('¤'), Some('$'), Finger::Index),
+            Key::Char('5', Some('%'), Some('€'), Finger::Index),
+            Key::Char('6', Some('&'), Some('¥'), Finger::Index),
+            Key::Char('7', Some('/'), Some('{'), Finger::Index),
+            Key::Char('8', Some('('), Some('['), Finger::Middle),
+            Key::Char('9', Some(')'), Some(']'), Finger::Ring),
+            Key::Char('0', Some('='), Some('}'), Finger::Pinky),
+            Key::Char('+', Some('?'), Some('\\'), Finger::Pinky),
+            Key::Char('´', Some('`'), Some('±'), Finger::Pinky),
+        ],
+        row0: [
+            Key::Char('q', Some('Q'), None, Finger::Pinky),
+            Key::Char('w', Some('W'), Some('ſ'), Finger::Ring),
+            Key::Char('e', Some('E'), None, Finger::Middle),
+            Key::Char('r', Some('R'), Some('®'), Finger::Index),
+            Key::Char('t', Some('T'), Some('þ'), Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('u', Some('U'), None, Finger::Index),
+            Key::Char('i', Some('I'), None, Finger::Middle),
+            Key::Char('o', Some('O'), Some('œ'), Finger::Ring),
+            Key::Char('p', Some('P'), None, Finger::Pinky),
+            Key::Char('å', Some('Å'), None, Finger::Pinky),
+            Key::Char('¨', Some('^'), Some('~'), Finger::Pinky),
+        ],
+        row1: [
+            Key::Char('a', Some('A'), Some('ª'), Finger::Pinky),
+            Key::Char('s', Some('S'), Some('ß'), Finger::Ring),
+            Key::Char('d', Some('D'), Some('ð'), Finger::Middle),
+            Key::Char('f', Some('F'), Some('đ'), Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('h', Some('H'), Some('ħ'), Finger::Index),
+            Key::Char('j', Some('J'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Middle),
+            Key::Char('l', Some('L'), Some('ł'), Finger::Ring),
+            Key::Char('ö', Some('Ö'), Some('ø'), Finger::Pinky),
+            Key::Char('ä', Some('Ä'), Some('æ'), Finger::Pinky),
+            Key::Char('\'', Some('*'), None, Finger::Pinky),
+        ],
+        row2: [
+            Key::Char('<', Some('>'), Some('|'), Finger::Pinky),
+            Key::Char('z', Some('Z'), Some('«'), Finger::Pinky),
+            Key::Char('x', Some('X'), Some('»'), Finger::Ring),
+            Key::Char('c', Some('C'), Some('©'), Finger::Middle),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('b', Some('B'), Some('“'), Finger::Index),
+            Key::Char('n', Some('N'), Some('”'), Finger::Index),
+            Key::Char('m', Some('M'), Some('µ'), Finger::Index),
+            Key::Char(',', Some(';'), Some('¸'), Finger::Middle),
+            Key::Char('.', Some(':'), None, Finger::Ring),
+            Key::Char('-', Some('_'), None, Finger::Pinky),
+        ],
+        dead_keys: vec!['^', '`', '~', '¨', '´', '¸'],
+        progression: Vec::new(),
+    }
+}
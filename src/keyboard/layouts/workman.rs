@@ -0,0 +1,128 @@
+use crate::keyboard::{AnsiKeyboardLayout, Finger, IsoKeyboardLayout, Key};
+
+pub fn iso() -> IsoKeyboardLayout {
+    IsoKeyboardLayout {
+        name: String::from("Workman"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), None, Finger::Pinky),
+            Key::Char('2', Some('@'), None, Finger::Ring),
+            Key::Char('3', Some('#'), None, Finger::Middle),
+            Key::Char('4', Some('$'), None, Finger::Index),
+            Key::Char('5', Some('%'), None, Finger::Index),
+            Key::Char('6', Some('^'), None, Finger::Index),
+            Key::Char('7', Some('&'), None, Finger::Index),
+            Key::Char('8', Some('*'), None, Finger::Middle),
+            Key::Char('9', Some('('), None, Finger::Ring),
+            Key::Char('0', Some(')'), None, Finger::Pinky),
+            Key::Char('-', Some('_'), None, Finger::Pinky),
+            Key::Char('=', Some('+'), None, Finger::Pinky),
+        ],
+        row0: [
+            Key::Char('q', Some('Q'), None, Finger::Pinky),
+            Key::Char('d', Some('D'), None, Finger::Ring),
+            Key::Char('r', Some('R'), None, Finger::Middle),
+            Key::Char('w', Some('W'), None, Finger::Index),
+            Key::Char('b', Some('B'), None, Finger::Index),
+            Key::Char('j', Some('J'), None, Finger::Index),
+            Key::Char('f', Some('F'), None, Finger::Index),
+            Key::Char('u', Some('U'), None, Finger::Middle),
+            Key::Char('p', Some('P'), None, Finger::Ring),
+            Key::Char(';', Some(':'), None, Finger::Pinky),
+            Key::Char('[', Some('{'), None, Finger::Pinky),
+            Key::Char(']', Some('}'), None, Finger::Pinky),
+        ],
+        row1: [
+            Key::Char('a', Some('A'), None, Finger::Pinky),
+            Key::Char('s', Some('S'), None, Finger::Ring),
+            Key::Char('h', Some('H'), None, Finger::Middle),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('n', Some('N'), None, Finger::Index),
+            Key::Char('e', Some('E'), None, Finger::Middle),
+            Key::Char('o', Some('O'), None, Finger::Ring),
+            Key::Char('i', Some('I'), None, Finger::Pinky),
+            Key::Char('\'', Some('"'), None, Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
+        ],
+        row2: [
+            Key::None,
+            Key::Char('z', Some('Z'), None, Finger::Pinky),
+            Key::Char('x', Some('X'), None, Finger::Ring),
+            Key::Char('m', Some('M'), None, Finger::Middle),
+            Key::Char('c', Some('C'), None, Finger::Index),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Index),
+            Key::Char('l', Some('L'), None, Finger::Index),
+            Key::Char(',', Some('<'), None, Finger::Middle),
+            Key::Char('.', Some('>'), None, Finger::Ring),
+            Key::Char('/', Some('?'), None, Finger::Pinky),
+        ],
+        dead_keys: Vec::new(),
+        progression: super::progression(&["ashtneoi", "dr", "fulm", "pwgy", "cbvk", "qjzx"]),
+    }
+}
+
+pub fn ansi() -> AnsiKeyboardLayout {
+    AnsiKeyboardLayout {
+        name: String::from("Workman"),
+        numbers: [
+            Key::Char('`', Some('~'), None, Finger::Pinky),
+            Key::Char('1', Some('!'), None, Finger::Pinky),
+            Key::Char('2', Some('@'), None, Finger::Ring),
+            Key::Char('3', Some('#'), None, Finger::Middle),
+            Key::Char('4', Some('$'), None, Finger::Index),
+            Key::Char('5', Some('%'), None, Finger::Index),
+            Key::Char('6', Some('^'), None, Finger::Index),
+            Key::Char('7', Some('&'), None, Finger::Index),
+            Key::Char('8', Some('*'), None, Finger::Middle),
+            Key::Char('9', Some('('), None, Finger::Ring),
+            Key::Char('0', Some(')'), None, Finger::Pinky),
+            Key::Char('-', Some('_'), None, Finger::Pinky),
+            Key::Char('=', Some('+'), None, Finger::Pinky),
+        ],
+        row0: [
+            Key::Char('q', Some('Q'), None, Finger::Pinky),
+            Key::Char('d', Some('D'), None, Finger::Ring),
+            Key::Char('r', Some('R'), None, Finger::Middle),
+            Key::Char('w', Some('W'), None, Finger::Index),
+            Key::Char('b', Some('B'), None, Finger::Index),
+            Key::Char('j', Some('J'), None, Finger::Index),
+            Key::Char('f', Some('F'), None, Finger::Index),
+            Key::Char('u', Some('U'), None, Finger::Middle),
+            Key::Char('p', Some('P'), None, Finger::Ring),
+            Key::Char(';', Some(':'), None, Finger::Pinky),
+            Key::Char('[', Some('{'), None, Finger::Pinky),
+            Key::Char(']', Some('}'), None, Finger::Pinky),
+            Key::Char('\\', Some('|'), None, Finger::Pinky),
+        ],
+        row1: [
+            Key::Char('a', Some('A'), None, Finger::Pinky),
+            Key::Char('s', Some('S'), None, Finger::Ring),
+            Key::Char('h', Some('H'), None, Finger::Middle),
+            Key::Char('t', Some('T'), None, Finger::Index),
+            Key::Char('g', Some('G'), None, Finger::Index),
+            Key::Char('y', Some('Y'), None, Finger::Index),
+            Key::Char('n', Some('N'), None, Finger::Index),
+            Key::Char('e', Some('E'), None, Finger::Middle),
+            Key::Char('o', Some('O'), None, Finger::Ring),
+            Key::Char('i', Some('I'), None, Finger::Pinky),
+            Key::Char('\'', Some('"'), None, Finger::Pinky),
+        ],
+        row2: [
+            Key::Char('z', Some('Z'), None, Finger::Pinky),
+            Key::Char('x', Some('X'), None, Finger::Ring),
+            Key::Char('m', Some('M'), None, Finger::Middle),
+            Key::Char('c', Some('C'), None, Finger::Index),
+            Key::Char('v', Some('V'), None, Finger::Index),
+            Key::Char('k', Some('K'), None, Finger::Index),
+            Key::Char('l', Some('L'), None, Finger::Index),
+            Key::Char(',', Some('<'), None, Finger::Middle),
+            Key::Char('.', Some('>'), None, Finger::Ring),
+            Key::Char('/', Some('?'), None, Finger::Pinky),
+        ],
+        dead_keys: Vec::new(),
+        progression: super::progression(&["ashtneoi", "dr", "fulm", "pwgy", "cbvk", "qjzx"]),
+    }
+}
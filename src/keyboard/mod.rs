@@ -1,4 +1,9 @@
 pub mod layouts;
 
+mod compose;
+mod import;
 mod keyboard;
+mod layout_file;
+pub use compose::*;
 pub use keyboard::*;
+pub use layout_file::*;
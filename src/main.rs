@@ -5,7 +5,7 @@ use crossterm::{
     ExecutableCommand,
 };
 use ratatui::{backend::CrosstermBackend, Terminal};
-use state::AppState;
+use state::{AppState, Profile};
 use ui::{screens::MenuScreen, App};
 
 mod keyboard;
@@ -13,16 +13,29 @@ mod state;
 mod ui;
 
 fn main() -> Result<()> {
+    let profile = Profile::load().unwrap_or_else(|err| {
+        eprintln!("Could not load profile, starting fresh: {}", err);
+        Profile::default()
+    });
+
+    let layout_errors = keyboard::layouts_dir()
+        .map(|dir| keyboard::layouts::load_custom(&dir))
+        .unwrap_or_default();
+
+    let mut state = AppState::from_profile(profile);
+    state.warnings = layout_errors.iter().map(|err| err.to_string()).collect();
+
     stdout().execute(EnterAlternateScreen)?;
     enable_raw_mode()?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
     terminal.clear()?;
 
-    let mut app = App::new(AppState::new(), MenuScreen::new());
+    let mut app = App::new(state, MenuScreen::new());
 
     app.main_loop(&mut terminal)?;
 
     stdout().execute(LeaveAlternateScreen)?;
     disable_raw_mode()?;
-    Ok(())
+
+    app.into_state().save()
 }
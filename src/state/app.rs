@@ -1,18 +1,153 @@
-use crate::keyboard::{layouts, KeyboardLayouts, Level};
+use std::{
+    collections::{BTreeMap, BTreeSet, HashSet},
+    io,
+};
+
+use crate::keyboard::{InputMode, KeyboardLayouts, Level};
+
+use super::{
+    Difficulty, MasteryProgress, MasteryThresholds, Profile, SessionLength, SessionResult,
+};
 
 pub struct AppState {
     pub level: Level,
+    pub completed_levels: BTreeMap<String, BTreeSet<Level>>,
     pub layouts: KeyboardLayouts,
+    pub input_mode: InputMode,
+    pub finger_hints: bool,
+    pub session_length: SessionLength,
+    pub capitals: bool,
+    pub shifted_symbols: bool,
+    pub adaptive: bool,
+    pub mastery: MasteryThresholds,
+    pub sessions: Vec<SessionResult>,
+    pub warnings: Vec<String>,
 }
 
 impl AppState {
-    pub fn new() -> Self {
+    pub fn from_profile(profile: Profile) -> Self {
+        let defaults = Profile::default();
+        let layouts = KeyboardLayouts::find(
+            profile.form_factor,
+            &profile.source_layout,
+            &profile.target_layout,
+        )
+        .or_else(|| {
+            KeyboardLayouts::find(
+                defaults.form_factor,
+                &defaults.source_layout,
+                &defaults.target_layout,
+            )
+        })
+        .expect("default layouts must exist");
+
         Self {
-            level: Level::One,
-            layouts: KeyboardLayouts::Iso {
-                source: layouts::qwertz::iso(),
-                target: layouts::colemak_dh::iso(),
-            },
+            level: profile.level,
+            completed_levels: profile.completed_levels,
+            layouts,
+            input_mode: profile.input_mode,
+            finger_hints: profile.finger_hints,
+            session_length: profile.session_length,
+            capitals: profile.capitals,
+            shifted_symbols: profile.shifted_symbols,
+            adaptive: profile.adaptive,
+            mastery: profile.mastery,
+            sessions: profile.sessions,
+            warnings: Vec::new(),
+        }
+    }
+
+    pub fn to_profile(&self) -> Profile {
+        Profile {
+            level: self.level,
+            completed_levels: self.completed_levels.clone(),
+            form_factor: self.layouts.form_factor(),
+            source_layout: self.layouts.source_name().to_string(),
+            target_layout: self.layouts.target_name().to_string(),
+            input_mode: self.input_mode,
+            finger_hints: self.finger_hints,
+            session_length: self.session_length,
+            capitals: self.capitals,
+            shifted_symbols: self.shifted_symbols,
+            adaptive: self.adaptive,
+            mastery: self.mastery,
+            sessions: self.sessions.clone(),
+        }
+    }
+
+    // The allowed letters plus the characters on their shift layer the
+    // word options ask for and the accented letters of unlocked dead keys
+    pub fn practice_letters(&self, level: Level) -> HashSet<char> {
+        let mut letters = self.layouts.allowed_target_letters(level);
+        let shifted = self.layouts.target_layout().shifted(&letters);
+        letters.extend(shifted.into_iter().filter(|ch| {
+            if ch.is_alphabetic() {
+                self.capitals
+            } else {
+                self.shifted_symbols
+            }
+        }));
+        let composed = self.layouts.target_layout().composed(&letters);
+        letters.extend(composed);
+        letters
+    }
+
+    // Words are picked uniformly if the word choice should not adapt, apart
+    // from favouring the letters that are new at this level
+    pub fn difficulty(&self, level: Level) -> Difficulty {
+        let difficulty = if self.adaptive {
+            Difficulty::from_sessions(&self.sessions, self.layouts.target_name())
+        } else {
+            Difficulty::default()
+        };
+        difficulty.with_new_letters(self.new_letters(level))
+    }
+
+    // The letters unlocked at this level that have not reached the mastery
+    // thresholds yet
+    pub fn new_letters(&self, level: Level) -> Vec<char> {
+        let lagging_keys = self.mastery_progress(level).lagging_keys;
+        self.layouts
+            .unlocked_target_letters(level)
+            .into_iter()
+            .filter(|ch| lagging_keys.contains(ch))
+            .collect()
+    }
+
+    pub fn mastery_progress(&self, level: Level) -> MasteryProgress {
+        MasteryProgress::evaluate(
+            &self.sessions,
+            level,
+            self.layouts.target_name(),
+            &self.layouts.allowed_target_letters(level),
+            &self.mastery,
+        )
+    }
+
+    // Levels are mastered separately on each target layout
+    pub fn is_completed(&self, level: Level) -> bool {
+        self.completed_levels
+            .get(self.layouts.target_name())
+            .is_some_and(|levels| levels.contains(&level))
+    }
+
+    // Mastering a level retried from the results screen never moves the
+    // current level back
+    pub fn advance_if_mastered(&mut self, level: Level) -> Option<Level> {
+        if !self.mastery_progress(level).is_mastered(&self.mastery) {
+            return None;
         }
+
+        self.completed_levels
+            .entry(self.layouts.target_name().to_string())
+            .or_default()
+            .insert(level);
+        let next = level.next().filter(|next| *next > self.level)?;
+        self.level = next;
+        Some(next)
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        self.to_profile().save()
     }
 }
@@ -0,0 +1,123 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::keyboard::lowercase;
+
+use super::{KeyStats, SessionResult};
+
+// Only the most recent sessions covering this many key presses count
+static RECENT_PRESSES: u32 = 2000;
+// Letters and bigrams need this many presses before they count as weak
+static MIN_PRESSES: u32 = 5;
+// One error in ten presses counts as much as being twice as slow as average
+static ERROR_WEIGHT: f64 = 10.0;
+// How much more often a word with average difficulty one is picked
+static FOCUS: f64 = 3.0;
+// How much more often a word with a newly unlocked letter is picked
+static NEW_LETTER_BOOST: f64 = 4.0;
+
+// How much harder than average the user finds each letter and bigram, zero
+// for anything that is not worse than average
+#[derive(Default)]
+pub struct Difficulty {
+    letters: HashMap<char, f64>,
+    bigrams: HashMap<(char, char), f64>,
+    new_letters: HashSet<char>,
+}
+
+impl Difficulty {
+    pub fn from_sessions(sessions: &[SessionResult], target_layout: &str) -> Self {
+        let mut letters: HashMap<char, KeyStats> = HashMap::new();
+        let mut bigrams: HashMap<(char, char), KeyStats> = HashMap::new();
+        let mut presses = 0;
+
+        // Stats from another layout say nothing about the keys of this one
+        let sessions = sessions
+            .iter()
+            .rev()
+            .filter(|s| s.target_layout == target_layout);
+        for session in sessions {
+            if presses >= RECENT_PRESSES {
+                break;
+            }
+
+            for (ch, stats) in &session.keys {
+                presses += stats.presses();
+                letters.entry(lowercase(*ch)).or_default().merge(stats);
+            }
+            for (bigram, stats) in &session.bigrams {
+                let mut chars = bigram.chars().map(lowercase);
+                if let (Some(first), Some(second)) = (chars.next(), chars.next()) {
+                    bigrams.entry((first, second)).or_default().merge(stats);
+                }
+            }
+        }
+
+        let (latency, timed) = letters.values().fold((0.0, 0), |(latency, timed), stats| {
+            (
+                latency + stats.total_latency.as_secs_f64(),
+                timed + stats.timed_presses,
+            )
+        });
+        let mean_latency = (timed > 0).then(|| latency / timed as f64);
+
+        Self {
+            letters: Self::scores(letters, mean_latency),
+            bigrams: Self::scores(bigrams, mean_latency),
+            new_letters: HashSet::new(),
+        }
+    }
+
+    // Letters the user has just unlocked and is not yet confident with
+    pub fn with_new_letters(mut self, letters: impl IntoIterator<Item = char>) -> Self {
+        self.new_letters = letters.into_iter().map(lowercase).collect();
+        self
+    }
+
+    pub fn new_letters(&self) -> &HashSet<char> {
+        &self.new_letters
+    }
+
+    fn scores<K: Eq + std::hash::Hash>(
+        stats: HashMap<K, KeyStats>,
+        mean_latency: Option<f64>,
+    ) -> HashMap<K, f64> {
+        stats
+            .into_iter()
+            .filter(|(_, stats)| stats.presses() >= MIN_PRESSES)
+            .map(|(key, stats)| {
+                let errors = 1.0 - stats.accuracy().unwrap_or(1.0);
+                let slowness = match (stats.mean_latency(), mean_latency) {
+                    (Some(latency), Some(mean)) if mean > 0.0 => {
+                        (latency.as_secs_f64() / mean - 1.0).max(0.0)
+                    }
+                    _ => 0.0,
+                };
+                (key, errors * ERROR_WEIGHT + slowness)
+            })
+            .collect()
+    }
+
+    // Words are weighted by the average difficulty of their letters and
+    // bigrams, so long words are not preferred
+    pub fn weight(&self, word: &str) -> f64 {
+        let chars = word.chars().map(lowercase).collect::<Vec<_>>();
+        if chars.is_empty() {
+            return 1.0;
+        }
+
+        let letters = chars
+            .iter()
+            .filter_map(|ch| self.letters.get(ch))
+            .sum::<f64>();
+        let bigrams = chars
+            .windows(2)
+            .filter_map(|pair| self.bigrams.get(&(pair[0], pair[1])))
+            .sum::<f64>();
+        let weight = 1.0 + FOCUS * (letters + bigrams) / chars.len() as f64;
+        if chars.iter().any(|ch| self.new_letters.contains(ch)) {
+            weight * NEW_LETTER_BOOST
+        } else {
+            weight
+        }
+    }
+}
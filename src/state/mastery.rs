@@ -0,0 +1,84 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::keyboard::Level;
+
+use super::{KeyStats, SessionResult};
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MasteryThresholds {
+    pub wpm: f64,
+    pub accuracy: f64,
+    pub words: u32,
+}
+
+pub struct MasteryProgress {
+    pub words: u32,
+    pub lagging_keys: Vec<char>,
+}
+
+impl Default for MasteryThresholds {
+    fn default() -> Self {
+        Self {
+            wpm: 25.0,
+            accuracy: 0.95,
+            words: 100,
+        }
+    }
+}
+
+impl MasteryProgress {
+    pub fn evaluate(
+        sessions: &[SessionResult],
+        level: Level,
+        target_layout: &str,
+        letters: &HashSet<char>,
+        thresholds: &MasteryThresholds,
+    ) -> Self {
+        // Only the most recent sessions covering the required number of words count
+        let mut words = 0;
+        let mut keys: HashMap<char, KeyStats> = HashMap::new();
+        for session in sessions
+            .iter()
+            .rev()
+            .filter(|s| s.level == level && s.target_layout == target_layout && !s.aborted)
+        {
+            if words >= thresholds.words {
+                break;
+            }
+
+            words += session.words;
+            for (ch, stats) in &session.keys {
+                keys.entry(*ch).or_default().merge(stats);
+            }
+        }
+
+        let mut lagging_keys = letters
+            .iter()
+            .filter(|ch| {
+                // Letters the words never asked for cannot hold back the level
+                let Some(stats) = keys.get(ch) else {
+                    return words < thresholds.words;
+                };
+                let accurate = stats
+                    .accuracy()
+                    .is_some_and(|accuracy| accuracy >= thresholds.accuracy);
+                let fast = stats.wpm().is_some_and(|wpm| wpm >= thresholds.wpm);
+                !accurate || !fast
+            })
+            .copied()
+            .collect::<Vec<_>>();
+        lagging_keys.sort();
+
+        Self {
+            words: words.min(thresholds.words),
+            lagging_keys,
+        }
+    }
+
+    pub fn is_mastered(&self, thresholds: &MasteryThresholds) -> bool {
+        self.words >= thresholds.words && self.lagging_keys.is_empty()
+    }
+}
@@ -6,9 +6,19 @@ use ratatui::{
 };
 
 mod app;
+mod difficulty;
+mod mastery;
+mod profile;
+mod pseudo_words;
+mod stats;
 mod word_list;
 
 pub use app::*;
+pub use difficulty::*;
+pub use mastery::*;
+pub use profile::*;
+pub use pseudo_words::*;
+pub use stats::*;
 pub use word_list::*;
 
 pub struct WordIter(<Vec<&'static str> as IntoIterator>::IntoIter);
@@ -24,6 +34,9 @@ impl Iterator for WordIter {
 pub struct WordInput {
     expected: String,
     entered: String,
+    // A dead key waiting to be combined with the next letter
+    pending: Option<char>,
+    mistyped: bool,
 }
 
 impl WordInput {
@@ -31,10 +44,15 @@ impl WordInput {
         Self {
             expected: expected.into(),
             entered: String::new(),
+            pending: None,
+            mistyped: false,
         }
     }
 
     pub fn push(&mut self, letter: char) {
+        if self.next_expected() != Some(letter) {
+            self.mistyped = true;
+        }
         self.entered.push(letter);
     }
 
@@ -42,6 +60,14 @@ impl WordInput {
         self.entered.pop();
     }
 
+    pub fn set_pending(&mut self, pending: Option<char>) {
+        self.pending = pending;
+    }
+
+    pub fn next_expected(&self) -> Option<char> {
+        self.expected.chars().nth(self.entered.chars().count())
+    }
+
     pub fn to_line(&self) -> Line<'static> {
         let mut spans = Vec::new();
         let mut current_ty = LetterType::Valid;
@@ -49,9 +75,24 @@ impl WordInput {
 
         let mut expected = self.expected.chars();
         let mut entered = self.entered.chars();
+        let mut pending = self.pending;
 
         loop {
-            match (expected.next(), entered.next()) {
+            let next = entered.next();
+            if let (None, Some(dead)) = (next, pending.take()) {
+                // The pending dead key takes the place of the next letter
+                expected.next();
+                Self::append(
+                    &mut spans,
+                    &mut current_ty,
+                    &mut current_buf,
+                    dead,
+                    LetterType::Pending,
+                );
+                continue;
+            }
+
+            match (expected.next(), next) {
                 (None, None) => break,
                 (None, Some(ent)) => Self::append(
                     &mut spans,
@@ -108,6 +149,24 @@ impl WordInput {
     pub fn is_correct(&self) -> bool {
         self.expected == self.entered
     }
+
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+}
+
+pub struct TypedWord {
+    pub word: String,
+    pub mistyped: bool,
+}
+
+impl From<&WordInput> for TypedWord {
+    fn from(input: &WordInput) -> Self {
+        Self {
+            word: input.expected.clone(),
+            mistyped: input.mistyped,
+        }
+    }
 }
 
 #[derive(PartialEq, Eq)]
@@ -115,6 +174,7 @@ enum LetterType {
     Valid,
     Invalid,
     Placeholder,
+    Pending,
 }
 
 impl LetterType {
@@ -123,6 +183,7 @@ impl LetterType {
             LetterType::Valid => Style::new(),
             LetterType::Invalid => Style::new().red(),
             LetterType::Placeholder => Style::new().dark_gray(),
+            LetterType::Pending => Style::new().yellow().underlined(),
         }
     }
 }
@@ -0,0 +1,217 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, HashMap},
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::PathBuf,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::keyboard::{FormFactor, InputMode, Level};
+
+use super::{KeyStats, MasteryThresholds, SessionStats, Summary};
+
+#[derive(Serialize, Deserialize)]
+#[serde(default)]
+pub struct Profile {
+    pub level: Level,
+    // Target layout name to the levels mastered on it
+    pub completed_levels: BTreeMap<String, BTreeSet<Level>>,
+    pub form_factor: FormFactor,
+    pub source_layout: String,
+    pub target_layout: String,
+    pub input_mode: InputMode,
+    pub finger_hints: bool,
+    pub session_length: SessionLength,
+    pub capitals: bool,
+    pub shifted_symbols: bool,
+    pub adaptive: bool,
+    pub mastery: MasteryThresholds,
+    pub sessions: Vec<SessionResult>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SessionLength {
+    Words(u32),
+    Seconds(u32),
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SessionResult {
+    pub finished_at: u64,
+    pub level: Level,
+    pub target_layout: String,
+    pub duration: Duration,
+    pub words: u32,
+    pub keys: HashMap<char, KeyStats>,
+    #[serde(default)]
+    pub bigrams: HashMap<String, KeyStats>,
+    // Sessions left early only count towards the key statistics
+    #[serde(default)]
+    pub aborted: bool,
+}
+
+impl Profile {
+    pub fn load() -> io::Result<Self> {
+        let path = Self::path()?;
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+
+        serde_json::from_str(&contents).map_err(|err| {
+            // Move the unreadable profile aside so the next save does not
+            // overwrite it
+            let backup = path.with_extension("json.bak");
+            match fs::rename(&path, &backup) {
+                Ok(()) => io::Error::new(
+                    ErrorKind::InvalidData,
+                    format!("{}, moved it to {}", err, backup.display()),
+                ),
+                Err(_) => io::Error::from(err),
+            }
+        })
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        let path = Self::path()?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Write a temporary file first so a crash mid-write keeps the old profile
+        let contents = serde_json::to_string_pretty(self)?;
+        let temp = path.with_extension("json.tmp");
+        fs::write(&temp, contents)?;
+        fs::rename(temp, path)
+    }
+
+    fn path() -> io::Result<PathBuf> {
+        let data_dir = dirs::data_dir().ok_or_else(|| {
+            io::Error::new(ErrorKind::NotFound, "could not determine data directory")
+        })?;
+        Ok(data_dir.join("colemak-trainer").join("profile.json"))
+    }
+}
+
+impl Default for Profile {
+    fn default() -> Self {
+        Self {
+            level: Level::One,
+            completed_levels: BTreeMap::new(),
+            form_factor: FormFactor::Iso,
+            source_layout: String::from("QWERTZ"),
+            target_layout: String::from("Colemak-DH"),
+            input_mode: InputMode::Remap,
+            finger_hints: true,
+            session_length: SessionLength::Words(25),
+            capitals: false,
+            shifted_symbols: false,
+            adaptive: true,
+            mastery: MasteryThresholds::default(),
+            sessions: Vec::new(),
+        }
+    }
+}
+
+impl SessionResult {
+    pub fn new(
+        level: Level,
+        target_layout: &str,
+        stats: &SessionStats,
+        duration: Duration,
+    ) -> Self {
+        let finished_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+
+        Self {
+            finished_at,
+            level,
+            target_layout: target_layout.to_string(),
+            duration,
+            words: stats.words(),
+            keys: stats.keys().clone(),
+            bigrams: stats.bigrams().clone(),
+            aborted: false,
+        }
+    }
+
+    pub fn summary(&self) -> Summary {
+        Summary::new(&self.keys, self.duration)
+    }
+
+    pub fn weakest_keys(&self, count: usize) -> Vec<(char, &KeyStats)> {
+        let mut keys = self
+            .keys
+            .iter()
+            .filter(|(_, stats)| stats.presses() > 0)
+            .map(|(ch, stats)| (*ch, stats))
+            .collect::<Vec<_>>();
+
+        keys.sort_by(|(_, a), (_, b)| {
+            let accuracy = a.accuracy().partial_cmp(&b.accuracy());
+            let latency = b.mean_latency().cmp(&a.mean_latency());
+            accuracy.unwrap_or(Ordering::Equal).then(latency)
+        });
+        keys.truncate(count);
+        keys
+    }
+
+    pub fn confusions(&self, count: usize) -> Vec<(char, char, u32)> {
+        let mut confusions = self
+            .keys
+            .iter()
+            .flat_map(|(expected, stats)| {
+                stats
+                    .confusions
+                    .iter()
+                    .map(|(actual, count)| (*expected, *actual, *count))
+            })
+            .collect::<Vec<_>>();
+
+        confusions.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+        confusions.truncate(count);
+        confusions
+    }
+}
+
+impl SessionLength {
+    const PRESETS: [SessionLength; 6] = [
+        SessionLength::Words(25),
+        SessionLength::Words(50),
+        SessionLength::Words(100),
+        SessionLength::Seconds(30),
+        SessionLength::Seconds(60),
+        SessionLength::Seconds(120),
+    ];
+
+    pub fn next(self) -> Self {
+        let presets = Self::PRESETS;
+        match presets.iter().position(|preset| *preset == self) {
+            Some(idx) => presets[(idx + 1) % presets.len()],
+            None => presets[0],
+        }
+    }
+
+    pub fn previous(self) -> Self {
+        let presets = Self::PRESETS;
+        match presets.iter().position(|preset| *preset == self) {
+            Some(idx) => presets[(idx + presets.len() - 1) % presets.len()],
+            None => presets[0],
+        }
+    }
+}
+
+impl fmt::Display for SessionLength {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SessionLength::Words(words) => write!(f, "{} words", words),
+            SessionLength::Seconds(seconds) => write!(f, "{} seconds", seconds),
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use rand::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+// Marks the start and the end of a word
+const BOUNDARY: char = '\0';
+
+static MIN_LENGTH: usize = 3;
+static MAX_LENGTH: usize = 8;
+
+// Generates pronounceable words from the letter sequences of real words,
+// using only the allowed letters
+pub struct PseudoWords {
+    // How often each letter follows two letters, and follows a single letter
+    trigrams: HashMap<(char, char), HashMap<char, u32>>,
+    bigrams: HashMap<char, HashMap<char, u32>>,
+    letters: Vec<char>,
+}
+
+impl PseudoWords {
+    pub fn new(words: &[&str], allowed_letters: &HashSet<char>) -> Self {
+        let mut trigrams: HashMap<_, HashMap<_, _>> = HashMap::new();
+        let mut bigrams: HashMap<_, HashMap<_, _>> = HashMap::new();
+
+        let words = words.iter().filter(|word| {
+            word.chars()
+                .all(|ch| ch.is_alphabetic() && ch.is_lowercase())
+        });
+        for word in words {
+            let chars = [BOUNDARY, BOUNDARY]
+                .into_iter()
+                .chain(word.chars())
+                .chain([BOUNDARY])
+                .collect::<Vec<_>>();
+
+            for window in chars.windows(3) {
+                let (first, second, next) = (window[0], window[1], window[2]);
+                *trigrams
+                    .entry((first, second))
+                    .or_default()
+                    .entry(next)
+                    .or_default() += 1;
+                *bigrams.entry(second).or_default().entry(next).or_default() += 1;
+            }
+        }
+
+        let mut letters = allowed_letters
+            .iter()
+            .copied()
+            .filter(|ch| ch.is_alphabetic() && ch.is_lowercase())
+            .collect::<Vec<_>>();
+        letters.sort_unstable();
+
+        Self {
+            trigrams,
+            bigrams,
+            letters,
+        }
+    }
+
+    pub fn generate(&self, rng: &mut impl Rng) -> Option<String> {
+        let mut word = String::new();
+        let mut length = 0;
+        let mut context = (BOUNDARY, BOUNDARY);
+
+        loop {
+            let next = self.next_letter(context, length, rng)?;
+            if next == BOUNDARY {
+                return Some(word);
+            }
+            word.push(next);
+            length += 1;
+            context = (context.1, next);
+        }
+    }
+
+    fn next_letter(
+        &self,
+        context: (char, char),
+        length: usize,
+        rng: &mut impl Rng,
+    ) -> Option<char> {
+        let allowed = |ch: char| {
+            if ch == BOUNDARY {
+                length >= MIN_LENGTH
+            } else {
+                length < MAX_LENGTH && self.letters.contains(&ch)
+            }
+        };
+
+        // Fall back to a single letter of context if no allowed letter ever
+        // followed both, and to any allowed letter after that
+        let candidates = [self.trigrams.get(&context), self.bigrams.get(&context.1)]
+            .into_iter()
+            .flatten()
+            .map(|counts| {
+                counts
+                    .iter()
+                    .filter(|(ch, _)| allowed(**ch))
+                    .map(|(ch, count)| (*ch, *count))
+                    .collect::<Vec<_>>()
+            })
+            .find(|candidates| !candidates.is_empty())
+            .unwrap_or_else(|| {
+                if length >= MIN_LENGTH {
+                    vec![(BOUNDARY, 1)]
+                } else {
+                    self.letters.iter().map(|ch| (*ch, 1)).collect()
+                }
+            });
+
+        candidates
+            .choose_weighted(rng, |(_, count)| *count)
+            .ok()
+            .map(|(ch, _)| *ch)
+    }
+}
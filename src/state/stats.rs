@@ -0,0 +1,277 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+// Pauses longer than this are not counted as latency of the next key
+static MAX_LATENCY: Duration = Duration::from_secs(2);
+
+// Standardized word length used for WPM calculations
+static CHARS_PER_WORD: f64 = 5.0;
+
+#[derive(Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct KeyStats {
+    pub hits: u32,
+    pub misses: u32,
+    pub confusions: HashMap<char, u32>,
+    pub total_latency: Duration,
+    pub timed_presses: u32,
+    // How long the key was held, if the terminal reports releases
+    pub total_dwell: Duration,
+    pub timed_releases: u32,
+    // Presses while another key was still held, out of the presses counted
+    // while the terminal reports releases
+    pub overlaps: u32,
+    pub tracked_presses: u32,
+}
+
+impl KeyStats {
+    pub fn presses(&self) -> u32 {
+        self.hits + self.misses
+    }
+
+    pub fn accuracy(&self) -> Option<f64> {
+        if self.presses() == 0 {
+            return None;
+        }
+        Some(self.hits as f64 / self.presses() as f64)
+    }
+
+    pub fn mean_latency(&self) -> Option<Duration> {
+        if self.timed_presses == 0 {
+            return None;
+        }
+        Some(self.total_latency / self.timed_presses)
+    }
+
+    pub fn mean_dwell(&self) -> Option<Duration> {
+        if self.timed_releases == 0 {
+            return None;
+        }
+        Some(self.total_dwell / self.timed_releases)
+    }
+
+    // The share of presses that rolled over from the previous key
+    pub fn rollover(&self) -> Option<f64> {
+        if self.tracked_presses == 0 {
+            return None;
+        }
+        Some(self.overlaps as f64 / self.tracked_presses as f64)
+    }
+
+    pub fn wpm(&self) -> Option<f64> {
+        let latency = self.mean_latency()?.as_secs_f64();
+        if latency <= 0.0 {
+            return None;
+        }
+        Some(60.0 / (latency * CHARS_PER_WORD))
+    }
+
+    pub fn merge(&mut self, other: &KeyStats) {
+        self.hits += other.hits;
+        self.misses += other.misses;
+        for (ch, count) in &other.confusions {
+            *self.confusions.entry(*ch).or_default() += count;
+        }
+        self.total_latency += other.total_latency;
+        self.timed_presses += other.timed_presses;
+        self.total_dwell += other.total_dwell;
+        self.timed_releases += other.timed_releases;
+        self.overlaps += other.overlaps;
+        self.tracked_presses += other.tracked_presses;
+    }
+}
+
+#[derive(Clone, Copy, Default)]
+pub struct Summary {
+    pub elapsed: Duration,
+    pub raw_wpm: f64,
+    pub net_wpm: f64,
+    pub accuracy: f64,
+}
+
+impl Summary {
+    pub fn new(keys: &HashMap<char, KeyStats>, elapsed: Duration) -> Self {
+        let (hits, presses) = keys.values().fold((0, 0), |(hits, presses), stats| {
+            (hits + stats.hits, presses + stats.presses())
+        });
+
+        let minutes = elapsed.as_secs_f64() / 60.0;
+        let wpm = |chars: u32| {
+            if minutes > 0.0 {
+                chars as f64 / CHARS_PER_WORD / minutes
+            } else {
+                0.0
+            }
+        };
+
+        Self {
+            elapsed,
+            raw_wpm: wpm(presses),
+            net_wpm: wpm(hits),
+            accuracy: if presses > 0 {
+                hits as f64 / presses as f64
+            } else {
+                1.0
+            },
+        }
+    }
+}
+
+struct HeldKey {
+    // The expected character the press was counted for
+    expected: Option<char>,
+    pressed_at: Instant,
+}
+
+pub struct SessionStats {
+    keys: HashMap<char, KeyStats>,
+    // Pairs of expected characters typed in a row, e.g. "th"
+    bigrams: HashMap<String, KeyStats>,
+    previous: Option<char>,
+    words: u32,
+    first_press: Option<Instant>,
+    last_press: Option<Instant>,
+    // Keys that are down, by the key reported on release
+    held: HashMap<char, HeldKey>,
+    // Whether the terminal reports key releases at all
+    releases: bool,
+}
+
+impl SessionStats {
+    pub fn new() -> Self {
+        Self {
+            keys: HashMap::new(),
+            bigrams: HashMap::new(),
+            previous: None,
+            words: 0,
+            first_press: None,
+            last_press: None,
+            held: HashMap::new(),
+            releases: false,
+        }
+    }
+
+    pub fn record(&mut self, expected: char, actual: char, at: Instant) {
+        let latency = self.latency(at);
+        let stats = self.keys.entry(expected).or_default();
+        Self::count(stats, expected, actual, latency);
+
+        if let Some(previous) = self.previous.replace(expected) {
+            let bigram = format!("{}{}", previous, expected);
+            let stats = self.bigrams.entry(bigram).or_default();
+            Self::count(stats, expected, actual, latency);
+        }
+    }
+
+    fn count(stats: &mut KeyStats, expected: char, actual: char, latency: Option<Duration>) {
+        if expected == actual {
+            stats.hits += 1;
+        } else {
+            stats.misses += 1;
+            *stats.confusions.entry(actual).or_default() += 1;
+        }
+
+        if let Some(latency) = latency {
+            stats.total_latency += latency;
+            stats.timed_presses += 1;
+        }
+    }
+
+    pub fn record_press(&mut self, key: char, expected: Option<char>, at: Instant) {
+        // Key repeats keep the first press
+        if !self.releases || self.held.contains_key(&key) {
+            return;
+        }
+
+        // Releases that never arrived, e.g. while the window lost focus
+        self.held
+            .retain(|_, held| at.saturating_duration_since(held.pressed_at) <= MAX_LATENCY);
+
+        if let Some(expected) = expected {
+            let stats = self.keys.entry(expected).or_default();
+            stats.tracked_presses += 1;
+            if !self.held.is_empty() {
+                stats.overlaps += 1;
+            }
+        }
+        self.held.insert(
+            key,
+            HeldKey {
+                expected,
+                pressed_at: at,
+            },
+        );
+    }
+
+    pub fn record_release(&mut self, key: char, at: Instant) {
+        // Presses before the first release can not be matched
+        self.releases = true;
+        let Some(released) = self.held.remove(&key) else {
+            return;
+        };
+
+        let dwell = at.saturating_duration_since(released.pressed_at);
+        if let Some(expected) = released.expected.filter(|_| dwell <= MAX_LATENCY) {
+            let stats = self.keys.entry(expected).or_default();
+            stats.total_dwell += dwell;
+            stats.timed_releases += 1;
+        }
+    }
+
+    pub fn record_other(&mut self, at: Instant) {
+        self.previous = None;
+        self.first_press.get_or_insert(at);
+        self.last_press = Some(at);
+    }
+
+    pub fn record_word(&mut self) {
+        self.words += 1;
+    }
+
+    pub fn keys(&self) -> &HashMap<char, KeyStats> {
+        &self.keys
+    }
+
+    pub fn bigrams(&self) -> &HashMap<String, KeyStats> {
+        &self.bigrams
+    }
+
+    pub fn words(&self) -> u32 {
+        self.words
+    }
+
+    pub fn duration(&self) -> Duration {
+        match (self.first_press, self.last_press) {
+            (Some(first), Some(last)) => last.saturating_duration_since(first),
+            _ => Duration::ZERO,
+        }
+    }
+
+    pub fn elapsed(&self, now: Instant) -> Duration {
+        self.first_press
+            .map(|first| now.saturating_duration_since(first))
+            .unwrap_or_default()
+    }
+
+    pub fn summary(&self, elapsed: Duration) -> Summary {
+        Summary::new(&self.keys, elapsed)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.first_press.is_none()
+    }
+
+    fn latency(&mut self, at: Instant) -> Option<Duration> {
+        self.first_press.get_or_insert(at);
+        let latency = self
+            .last_press
+            .map(|last| at.saturating_duration_since(last))
+            .filter(|latency| *latency <= MAX_LATENCY);
+        self.last_press = Some(at);
+        latency
+    }
+}
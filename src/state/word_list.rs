@@ -1,14 +1,42 @@
 use include_lines::include_lines;
-use rand::prelude::*;
-use std::collections::HashSet;
+use rand::{distributions::WeightedIndex, prelude::*};
+use std::collections::{HashSet, VecDeque};
+
+use crate::keyboard::{lowercase, uppercase};
+
+use super::{Difficulty, PseudoWords};
+
+static LOOKAHEAD: usize = 8;
+static NUMBER_CHANCE: f64 = 0.15;
+static SYMBOL_CHANCE: f64 = 0.3;
+static CAPITAL_CHANCE: f64 = 0.15;
+// With fewer matching words than this, pseudo-words fill the gap
+static ENOUGH_WORDS: usize = 100;
+// Pseudo-words to choose from by their difficulty
+static PSEUDO_CANDIDATES: usize = 3;
+// Pseudo-words to try when looking for one with a particular letter
+static PSEUDO_ATTEMPTS: usize = 20;
+
+static BRACKETS: [(char, char); 5] = [('(', ')'), ('[', ']'), ('{', '}'), ('<', '>'), ('«', '»')];
 
 pub struct WordList {
     words: Vec<&'static str>,
+    // Words with weak letters and bigrams are picked more often
+    weights: Option<WeightedIndex<f64>>,
+    difficulty: Difficulty,
+    pseudo_words: PseudoWords,
+    pseudo_chance: f64,
+    symbols: Vec<char>,
+    digits: Vec<char>,
+    lowercase: Vec<char>,
+    capitals: HashSet<char>,
+    sentence_start: bool,
+    upcoming: VecDeque<String>,
     rng: rand::rngs::ThreadRng,
 }
 
 impl WordList {
-    pub fn new(allowed_letters: &HashSet<char>) -> Self {
+    pub fn new(allowed_letters: &HashSet<char>, difficulty: Difficulty) -> Self {
         const WORDS: [&'static str; 4974] = include_lines!("res/words.en.txt");
 
         let rng = rand::thread_rng();
@@ -16,11 +44,51 @@ impl WordList {
             .into_iter()
             .filter(|w| Self::is_valid(w, allowed_letters))
             .collect::<Vec<_>>();
+        let weights = WeightedIndex::new(matching.iter().map(|word| difficulty.weight(word))).ok();
+        let pseudo_words = PseudoWords::new(&WORDS, allowed_letters);
+        let pseudo_chance = 1.0 - (matching.len() as f64 / ENOUGH_WORDS as f64).min(1.0);
 
-        Self {
+        let mut symbols = allowed_letters
+            .iter()
+            .copied()
+            .filter(|ch| !ch.is_alphanumeric())
+            .collect::<Vec<_>>();
+        symbols.sort_unstable();
+        let mut digits = allowed_letters
+            .iter()
+            .copied()
+            .filter(char::is_ascii_digit)
+            .collect::<Vec<_>>();
+        digits.sort_unstable();
+        let mut lowercase = allowed_letters
+            .iter()
+            .copied()
+            .filter(|ch| ch.is_lowercase())
+            .collect::<Vec<_>>();
+        lowercase.sort_unstable();
+
+        let capitals = allowed_letters
+            .iter()
+            .copied()
+            .filter(|ch| ch.is_uppercase())
+            .collect();
+
+        let mut word_list = Self {
             words: matching,
+            weights,
+            difficulty,
+            pseudo_words,
+            pseudo_chance,
+            symbols,
+            digits,
+            lowercase,
+            capitals,
+            sentence_start: true,
+            upcoming: VecDeque::with_capacity(LOOKAHEAD),
             rng,
-        }
+        };
+        word_list.fill_upcoming();
+        word_list
     }
 
     fn is_valid(word: &str, allowed_letters: &HashSet<char>) -> bool {
@@ -32,7 +100,151 @@ impl WordList {
         true
     }
 
-    pub fn next_word(&mut self) -> &'static str {
-        self.words.choose(&mut self.rng).unwrap_or(&"hello")
+    pub fn next_word(&mut self) -> String {
+        let word = self
+            .upcoming
+            .pop_front()
+            .unwrap_or_else(|| self.random_word());
+        self.fill_upcoming();
+        word
+    }
+
+    pub fn upcoming(&self) -> impl Iterator<Item = &str> + '_ {
+        self.upcoming.iter().map(String::as_str)
+    }
+
+    fn fill_upcoming(&mut self) {
+        while self.upcoming.len() < LOOKAHEAD {
+            let word = self.random_word();
+            self.upcoming.push_back(word);
+        }
+    }
+
+    fn random_word(&mut self) -> String {
+        let word = match self.word_with_missing_letter() {
+            Some(word) => word,
+            None if !self.digits.is_empty() && self.rng.gen_bool(NUMBER_CHANCE) => {
+                return self.random_number();
+            }
+            None => self.pick_word(),
+        };
+        let word = if self.sentence_start || self.rng.gen_bool(CAPITAL_CHANCE) {
+            self.capitalize(&word)
+        } else {
+            word
+        };
+
+        let word = self.decorate(word);
+        self.sentence_start = word.ends_with(['.', '!', '?']);
+        word
+    }
+
+    fn capitalize(&self, word: &str) -> String {
+        let mut chars = word.chars();
+        match chars.next().map(uppercase) {
+            Some(first) if self.capitals.contains(&first) => first.to_string() + chars.as_str(),
+            _ => word.to_string(),
+        }
+    }
+
+    fn decorate(&mut self, word: String) -> String {
+        if self.symbols.is_empty() || !self.rng.gen_bool(SYMBOL_CHANCE) {
+            return word;
+        }
+
+        let symbol = self.symbols[self.rng.gen_range(0..self.symbols.len())];
+        let brackets = BRACKETS
+            .iter()
+            .find(|(open, close)| *open == symbol || *close == symbol)
+            .filter(|(open, close)| self.symbols.contains(open) && self.symbols.contains(close));
+
+        match (symbol, brackets) {
+            (_, Some((open, close))) => format!("{}{}{}", open, word, close),
+            ('\'' | '"' | '`', _) => format!("{}{}{}", symbol, word, symbol),
+            ('-' | '/' | '\\' | '_' | '=' | '+' | '&' | '|', _) => {
+                format!("{}{}{}", word, symbol, self.pick_word())
+            }
+            _ => format!("{}{}", word, symbol),
+        }
+    }
+
+    fn random_number(&mut self) -> String {
+        let len = self.rng.gen_range(1..=4);
+        (0..len)
+            .map(|_| self.digits[self.rng.gen_range(0..self.digits.len())])
+            .collect()
+    }
+
+    // Boosting new letters only makes their words likelier, so a word is
+    // forced in for each new letter the upcoming words lack
+    fn word_with_missing_letter(&mut self) -> Option<String> {
+        let missing = self
+            .difficulty
+            .new_letters()
+            .iter()
+            .copied()
+            .filter(|ch| {
+                !self
+                    .upcoming
+                    .iter()
+                    .any(|word| word.chars().map(lowercase).any(|other| other == *ch))
+            })
+            .min();
+        missing.map(|letter| self.pick_word_with(letter))
+    }
+
+    fn pick_word_with(&mut self, letter: char) -> String {
+        let words = self
+            .words
+            .iter()
+            .filter(|word| word.contains(letter))
+            .collect::<Vec<_>>();
+        if let Ok(word) = words.choose_weighted(&mut self.rng, |word| self.difficulty.weight(word))
+        {
+            return word.to_string();
+        }
+
+        let pseudo_word = (0..PSEUDO_ATTEMPTS)
+            .filter_map(|_| self.pseudo_words.generate(&mut self.rng))
+            .find(|word| word.contains(letter));
+        pseudo_word.unwrap_or_else(|| {
+            // Letters no word pattern allows, like q without u
+            let mut word = self.random_letters().chars().collect::<Vec<_>>();
+            word.insert(self.rng.gen_range(0..=word.len()), letter);
+            word.into_iter().collect()
+        })
+    }
+
+    // The last resort when neither words nor pseudo-words fit the letters
+    fn random_letters(&mut self) -> String {
+        let len = self.rng.gen_range(2..=5);
+        (0..len)
+            .filter_map(|_| self.lowercase.choose(&mut self.rng).copied())
+            .collect()
+    }
+
+    fn pick_word(&mut self) -> String {
+        if self.rng.gen_bool(self.pseudo_chance) {
+            if let Some(word) = self.pick_pseudo_word() {
+                return word;
+            }
+        }
+
+        match &self.weights {
+            Some(weights) => self.words[weights.sample(&mut self.rng)].to_string(),
+            None => self
+                .pick_pseudo_word()
+                .unwrap_or_else(|| self.random_letters()),
+        }
+    }
+
+    fn pick_pseudo_word(&mut self) -> Option<String> {
+        let candidates = (0..PSEUDO_CANDIDATES)
+            .filter_map(|_| self.pseudo_words.generate(&mut self.rng))
+            .collect::<Vec<_>>();
+        candidates
+            .choose_weighted(&mut self.rng, |word| self.difficulty.weight(word))
+            .ok()
+            .cloned()
     }
 }
@@ -1,8 +1,12 @@
 use std::{io::Result, time::Duration};
 
-use crossterm::event::{self, Event, KeyEvent};
+use crossterm::event::{self, Event, KeyEvent, KeyEventKind};
 use ratatui::{widgets::Widget, Frame, Terminal};
 
+use crate::keyboard::PhysicalKey;
+
+use super::kitty::KittyInput;
+
 pub struct App<State> {
     state: State,
     current_screen: Box<dyn Screen<AppState = State>>,
@@ -14,10 +18,16 @@ pub trait Screen {
     fn render(&mut self, ctx: &mut RenderContext<'_, '_, '_, Self::AppState>);
 
     fn handle_event(&mut self, ctx: &mut EventContext<'_, Self::AppState>, event: KeyEvent);
+
+    fn handle_release(&mut self, _ctx: &mut EventContext<'_, Self::AppState>, _event: KeyEvent) {}
+
+    fn tick(&mut self, _ctx: &mut EventContext<'_, Self::AppState>) {}
 }
 
 pub struct EventContext<'a, State> {
     pub state: &'a mut State,
+    // The key that was pressed, if the terminal reports it
+    pub physical_key: Option<PhysicalKey>,
     next_screen: Option<Box<dyn Screen<AppState = State>>>,
     should_quit: bool,
 }
@@ -41,6 +51,7 @@ impl<State> App<State> {
         Backend: ratatui::backend::Backend,
     {
         let state = &mut self.state;
+        let mut kitty = KittyInput::enable()?;
         loop {
             let screen = &mut self.current_screen;
 
@@ -49,29 +60,52 @@ impl<State> App<State> {
                 screen.render(&mut ctx);
             })?;
 
-            if event::poll(Duration::from_millis(16))? {
-                if let Event::Key(event) = event::read()? {
-                    let screen = &mut self.current_screen;
-                    let mut ctx = EventContext {
-                        state,
-                        next_screen: None,
-                        should_quit: false,
-                    };
+            let screen = &mut self.current_screen;
+            let mut ctx = EventContext {
+                state,
+                physical_key: None,
+                next_screen: None,
+                should_quit: false,
+            };
+
+            let event = match &mut kitty {
+                Some(kitty) => kitty.read(Duration::from_millis(16))?,
+                None if event::poll(Duration::from_millis(16))? => match event::read()? {
+                    Event::Key(event) => Some((event, None)),
+                    _ => None,
+                },
+                None => None,
+            };
+
+            // Windows and the kitty protocol report key releases as well
+            if let Some((event, physical_key)) = event {
+                ctx.physical_key = physical_key;
+                if event.kind == KeyEventKind::Release {
+                    screen.handle_release(&mut ctx, event);
+                } else {
                     screen.handle_event(&mut ctx, event);
+                }
+            }
 
-                    if ctx.should_quit {
-                        break;
-                    }
+            if ctx.next_screen.is_none() && !ctx.should_quit {
+                screen.tick(&mut ctx);
+            }
 
-                    if let Some(next_screen) = ctx.next_screen {
-                        self.current_screen = next_screen;
-                    }
-                }
+            if ctx.should_quit {
+                break;
+            }
+
+            if let Some(next_screen) = ctx.next_screen {
+                self.current_screen = next_screen;
             }
         }
 
         Ok(())
     }
+
+    pub fn into_state(self) -> State {
+        self.state
+    }
 }
 
 impl<'a, State> EventContext<'a, State> {
@@ -3,6 +3,7 @@ use ratatui::style::Color;
 pub struct ColorPalette {
     pub placeholder: Color,
     pub fingers: FingerColors,
+    pub presses: PressColors,
 }
 
 pub struct FingerColors {
@@ -13,6 +14,12 @@ pub struct FingerColors {
     pub thumb: Color,
 }
 
+pub struct PressColors {
+    pub correct: Color,
+    pub wrong: Color,
+    pub expected: Color,
+}
+
 impl Default for ColorPalette {
     fn default() -> Self {
         Self {
@@ -25,6 +32,12 @@ impl Default for ColorPalette {
                 index: Color::Green,
                 thumb: Color::Red,
             },
+
+            presses: PressColors {
+                correct: Color::LightGreen,
+                wrong: Color::LightRed,
+                expected: Color::LightBlue,
+            },
         }
     }
 }
@@ -1,8 +1,13 @@
+use std::time::{Duration, Instant};
+
 use ratatui::layout::Size;
 use ratatui::prelude::*;
 use ratatui::widgets::{Block, BorderType, Widget};
 
-use crate::keyboard::{self, AnsiKeyboardLayout, AnyKeyboardLayout, IsoKeyboardLayout};
+use crate::keyboard::{
+    self, AnsiKeyboardLayout, AnyKeyboardLayout, Finger, Hand, IsoKeyboardLayout, KeyPosition,
+    NUMBER_ROW,
+};
 
 use super::colors::ColorPalette;
 use super::iso_enter::IsoEnter;
@@ -18,6 +23,8 @@ static SIZES: KeySizes = KeySizes {
     u2_25: Size::new(11, KEY_HEIGHT),
 };
 
+static FLASH_DURATION: Duration = Duration::from_millis(400);
+
 static BOARD_SIZE: Size = Size {
     width: SIZES.u1.width * 13 + SIZES.u2.width,
     height: 5 * SIZES.u1.height,
@@ -27,6 +34,11 @@ pub struct Keyboard<'a> {
     layout: AnyKeyboardLayout<'a>,
     colors: &'a ColorPalette,
     origin: Origin,
+    highlight: Option<(KeyPosition, Finger, Hand)>,
+    highlight_char: Option<char>,
+    finger_hint: bool,
+    altgr_legend: bool,
+    flashes: Vec<(KeyPosition, Emphasis)>,
 }
 
 impl<'a> Keyboard<'a> {
@@ -35,6 +47,52 @@ impl<'a> Keyboard<'a> {
             layout,
             colors,
             origin: Default::default(),
+            highlight: None,
+            highlight_char: None,
+            finger_hint: false,
+            altgr_legend: false,
+            flashes: Vec::new(),
+        }
+    }
+
+    pub fn highlight(mut self, ch: Option<char>) -> Self {
+        self.highlight = ch
+            .and_then(|ch| self.layout.find(ch))
+            .map(|(position, finger)| (position, finger, self.layout.hand(position)));
+        self.highlight_char = ch;
+        self
+    }
+
+    pub fn finger_hint(mut self, enabled: bool) -> Self {
+        self.finger_hint = enabled;
+        self
+    }
+
+    pub fn altgr_legend(mut self, enabled: bool) -> Self {
+        self.altgr_legend = enabled;
+        self
+    }
+
+    pub fn flash(mut self, flash: Option<&KeyFlash>, now: Instant) -> Self {
+        let Some(flash) = flash.filter(|flash| !flash.is_over(now)) else {
+            return self;
+        };
+
+        let fresh = flash.is_fresh(now);
+        if flash.is_correct() {
+            self.push_flash(flash.pressed, self.colors.presses.correct, fresh);
+        } else {
+            self.push_flash(flash.pressed, self.colors.presses.wrong, fresh);
+            if let Some(expected) = flash.expected {
+                self.push_flash(expected, self.colors.presses.expected, fresh);
+            }
+        }
+        self
+    }
+
+    fn push_flash(&mut self, ch: char, color: Color, fresh: bool) {
+        if let Some((position, _)) = self.layout.find(ch) {
+            self.flashes.push((position, Emphasis::Flash(color, fresh)));
         }
     }
 
@@ -44,9 +102,13 @@ impl<'a> Keyboard<'a> {
             y: area.y,
         };
 
-        // Render top row
-        for _ in 0..13 {
-            self.render_key(keyboard::Key::None, SIZES.u1, buf);
+        // Render number row
+        let numbers = match self.layout {
+            AnyKeyboardLayout::Iso(layout) => layout.numbers,
+            AnyKeyboardLayout::Ansi(layout) => layout.numbers,
+        };
+        for (col, sym) in numbers.into_iter().enumerate() {
+            self.render_layout_key(KeyPosition::new(NUMBER_ROW, col), sym, SIZES.u1, buf);
         }
         self.render_key(keyboard::Key::None, SIZES.u2, buf);
         self.next_row(&area);
@@ -73,8 +135,8 @@ impl<'a> Keyboard<'a> {
     fn render_iso(&mut self, layout: &IsoKeyboardLayout, area: Rect, buf: &mut Buffer) {
         // Render first key row
         self.render_key(keyboard::Key::None, SIZES.u1_5, buf);
-        for sym in layout.row0 {
-            self.render_key(sym, SIZES.u1, buf);
+        for (col, sym) in layout.row0.into_iter().enumerate() {
+            self.render_layout_key(KeyPosition::new(0, col), sym, SIZES.u1, buf);
         }
 
         let iso_enter_width = self.remaining_key_size(area).width;
@@ -98,15 +160,15 @@ impl<'a> Keyboard<'a> {
 
         // Render second key row
         self.render_key(keyboard::Key::None, SIZES.u1_75, buf);
-        for sym in layout.row1 {
-            self.render_key(sym, SIZES.u1, buf);
+        for (col, sym) in layout.row1.into_iter().enumerate() {
+            self.render_layout_key(KeyPosition::new(1, col), sym, SIZES.u1, buf);
         }
         self.next_row(&area);
 
         // Render third key row
         self.render_key(keyboard::Key::None, SIZES.u1_25, buf);
-        for sym in layout.row2 {
-            self.render_key(sym, SIZES.u1, buf);
+        for (col, sym) in layout.row2.into_iter().enumerate() {
+            self.render_layout_key(KeyPosition::new(2, col), sym, SIZES.u1, buf);
         }
         let rshift_size = self.remaining_key_size(area);
         self.render_key(keyboard::Key::None, rshift_size, buf);
@@ -116,17 +178,17 @@ impl<'a> Keyboard<'a> {
         // Render first key row
         self.render_key(keyboard::Key::None, SIZES.u1_5, buf);
         let (last, rest) = layout.row0.split_last().expect("cannot be empty");
-        for sym in rest {
-            self.render_key(*sym, SIZES.u1, buf);
+        for (col, sym) in rest.iter().enumerate() {
+            self.render_layout_key(KeyPosition::new(0, col), *sym, SIZES.u1, buf);
         }
         let last_key_size = self.remaining_key_size(area);
-        self.render_key(*last, last_key_size, buf);
+        self.render_layout_key(KeyPosition::new(0, rest.len()), *last, last_key_size, buf);
         self.next_row(&area);
 
         // Render second key row
         self.render_key(keyboard::Key::None, SIZES.u1_75, buf);
-        for sym in layout.row1 {
-            self.render_key(sym, SIZES.u1, buf);
+        for (col, sym) in layout.row1.into_iter().enumerate() {
+            self.render_layout_key(KeyPosition::new(1, col), sym, SIZES.u1, buf);
         }
         let enter_size = self.remaining_key_size(area);
         self.render_key(keyboard::Key::None, enter_size, buf);
@@ -134,17 +196,75 @@ impl<'a> Keyboard<'a> {
 
         // Render third key row
         self.render_key(keyboard::Key::None, SIZES.u2_25, buf);
-        for sym in layout.row2 {
-            self.render_key(sym, SIZES.u1, buf);
+        for (col, sym) in layout.row2.into_iter().enumerate() {
+            self.render_layout_key(KeyPosition::new(2, col), sym, SIZES.u1, buf);
         }
         let rshift_size = self.remaining_key_size(area);
         self.render_key(keyboard::Key::None, rshift_size, buf);
     }
 
     fn render_key(&mut self, key: keyboard::Key, size: Size, buf: &mut Buffer) {
+        self.render_key_with_emphasis(key, Emphasis::None, size, buf);
+    }
+
+    fn render_layout_key(
+        &mut self,
+        position: KeyPosition,
+        key: keyboard::Key,
+        size: Size,
+        buf: &mut Buffer,
+    ) {
+        let flash = self
+            .flashes
+            .iter()
+            .find(|(flash_position, _)| *flash_position == position)
+            .map(|(_, emphasis)| *emphasis);
+
+        let emphasis = if let Some(flash) = flash {
+            flash
+        } else {
+            match (self.highlight, key) {
+                (Some((target, _, _)), _) if target == position => Emphasis::Target,
+                (Some((_, finger, hand)), keyboard::Key::Char(_, _, _, key_finger))
+                    if self.finger_hint
+                        && finger == key_finger
+                        && hand == self.layout.hand(position) =>
+                {
+                    Emphasis::Finger
+                }
+                _ => Emphasis::None,
+            }
+        };
+
+        // The key to type always shows the character to type, whichever layer
+        // it is on. The other keys only show the AltGr layer in legend mode.
+        let is_target = self
+            .highlight
+            .is_some_and(|(target, _, _)| target == position);
+        let key = match (key, self.highlight_char) {
+            (keyboard::Key::Char(_, _, _, finger), Some(ch)) if is_target => {
+                keyboard::Key::Char(ch, None, None, finger)
+            }
+            (keyboard::Key::Char(_, _, altgr, finger), _) if self.altgr_legend => match altgr {
+                Some(altgr) => keyboard::Key::Char(altgr, None, None, finger),
+                None => keyboard::Key::None,
+            },
+            (key, _) => key,
+        };
+
+        self.render_key_with_emphasis(key, emphasis, size, buf);
+    }
+
+    fn render_key_with_emphasis(
+        &mut self,
+        key: keyboard::Key,
+        emphasis: Emphasis,
+        size: Size,
+        buf: &mut Buffer,
+    ) {
         let area = Rect::new(self.origin.x, self.origin.y, size.width, size.height);
 
-        Key::new(key, self.colors).render(area, buf);
+        Key::new(key, emphasis, self.colors).render(area, buf);
 
         self.origin.x += size.width;
     }
@@ -179,16 +299,53 @@ impl Widget for Keyboard<'_> {
     }
 }
 
+pub struct KeyFlash {
+    pressed: char,
+    expected: Option<char>,
+    started: Instant,
+}
+
+impl KeyFlash {
+    pub fn new(pressed: char, expected: Option<char>, at: Instant) -> Self {
+        Self {
+            pressed,
+            expected,
+            started: at,
+        }
+    }
+
+    fn is_correct(&self) -> bool {
+        self.expected == Some(self.pressed)
+    }
+
+    pub fn is_over(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.started) >= FLASH_DURATION
+    }
+
+    fn is_fresh(&self, now: Instant) -> bool {
+        now.saturating_duration_since(self.started) < FLASH_DURATION / 2
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Emphasis {
+    None,
+    Target,
+    Finger,
+    Flash(Color, bool),
+}
+
 struct Key {
     text: Option<String>,
     color: Color,
+    emphasis: Emphasis,
 }
 
 impl Key {
-    pub fn new(key: keyboard::Key, colors: &ColorPalette) -> Self {
-        let (text, color) = if let keyboard::Key::Char(sym, finger) = key {
+    pub fn new(key: keyboard::Key, emphasis: Emphasis, colors: &ColorPalette) -> Self {
+        let (text, color) = if let keyboard::Key::Char(_, _, _, finger) = key {
             (
-                Some(sym.to_string()),
+                key.label().map(String::from),
                 match finger {
                     keyboard::Finger::Pinky => colors.fingers.pinky,
                     keyboard::Finger::Ring => colors.fingers.ring,
@@ -200,24 +357,47 @@ impl Key {
         } else {
             (None, colors.placeholder)
         };
-        Self { text, color }
+        Self {
+            text,
+            color,
+            emphasis,
+        }
     }
 }
 
 impl Widget for Key {
     fn render(self, area: Rect, buf: &mut Buffer) {
+        let color = match self.emphasis {
+            Emphasis::Flash(color, _) => color,
+            _ => self.color,
+        };
+
+        let (border_type, text_style) = match self.emphasis {
+            Emphasis::None => (BorderType::Rounded, Style::new().fg(self.color)),
+            Emphasis::Finger => (BorderType::Thick, Style::new().fg(self.color)),
+            Emphasis::Target => (
+                BorderType::Thick,
+                Style::new().fg(self.color).reversed().bold(),
+            ),
+            Emphasis::Flash(color, true) => {
+                (BorderType::Thick, Style::new().fg(color).reversed().bold())
+            }
+            Emphasis::Flash(color, false) => (BorderType::Rounded, Style::new().fg(color)),
+        };
+
         Block::bordered()
-            .border_type(BorderType::Rounded)
-            .border_style(self.color)
+            .border_type(border_type)
+            .border_style(color)
             .render(area, buf);
 
         if let Some(text) = self.text {
-            let text_x = area.x + ((area.width - 2 /* Border */ - (text.len() as u16)) / 2) + 1 /* Border */;
+            let text_width = text.chars().count() as u16;
+            let text_x = area.x + ((area.width - 2 /* Border */ - text_width) / 2) + 1 /* Border */;
             let text_y = area.y + (area.height - 1/* Line Height */) / 2;
 
             Text::raw(&text)
-                .style(self.color)
-                .render(Rect::new(text_x, text_y, text.len() as u16, 1), buf);
+                .style(text_style)
+                .render(Rect::new(text_x, text_y, text_width, 1), buf);
         }
     }
 }
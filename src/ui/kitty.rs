@@ -0,0 +1,348 @@
+use std::{io, time::Duration};
+
+use crossterm::{
+    event::{
+        KeyCode, KeyEvent, KeyEventKind, KeyEventState, KeyModifiers, KeyboardEnhancementFlags,
+        PopKeyboardEnhancementFlags, PushKeyboardEnhancementFlags,
+    },
+    execute, terminal,
+};
+
+use crate::keyboard::{decompose, uppercase, PhysicalKey};
+
+// crossterm does not parse associated text yet, so it has no constant for it
+const REPORT_ASSOCIATED_TEXT: u8 = 0b1_0000;
+
+// Reads key events with the kitty keyboard protocol. Besides the character a
+// key produces, terminals report the key it has on a US layout, which tells
+// the physical key regardless of the OS layout. crossterm parses the protocol
+// as well, but drops that key.
+pub struct KittyInput {
+    buffer: Vec<u8>,
+}
+
+impl KittyInput {
+    // Returns `None` if the terminal does not support the protocol
+    pub fn enable() -> io::Result<Option<Self>> {
+        if !terminal::supports_keyboard_enhancement()? {
+            return Ok(None);
+        }
+
+        let flags = KeyboardEnhancementFlags::DISAMBIGUATE_ESCAPE_CODES
+            | KeyboardEnhancementFlags::REPORT_EVENT_TYPES
+            | KeyboardEnhancementFlags::REPORT_ALTERNATE_KEYS
+            | KeyboardEnhancementFlags::REPORT_ALL_KEYS_AS_ESCAPE_CODES
+            | KeyboardEnhancementFlags::from_bits_retain(REPORT_ASSOCIATED_TEXT);
+        execute!(io::stdout(), PushKeyboardEnhancementFlags(flags))?;
+        Ok(Some(Self { buffer: Vec::new() }))
+    }
+
+    pub fn read(
+        &mut self,
+        timeout: Duration,
+    ) -> io::Result<Option<(KeyEvent, Option<PhysicalKey>)>> {
+        if let Some(event) = self.parse() {
+            return Ok(Some(event));
+        }
+
+        let mut chunk = [0; 1024];
+        let len = read_stdin(&mut chunk, timeout)?;
+        self.buffer.extend_from_slice(&chunk[..len]);
+
+        // An escape byte that is not followed by the rest of a sequence is the
+        // key itself
+        if len == 0 && matches!(self.buffer.as_slice(), [0x1b] | [0x1b, b'O']) {
+            self.buffer.remove(0);
+            return Ok(Some((
+                KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE),
+                None,
+            )));
+        }
+        Ok(self.parse())
+    }
+
+    fn parse(&mut self) -> Option<(KeyEvent, Option<PhysicalKey>)> {
+        loop {
+            match self.buffer.as_slice() {
+                [] | [0x1b] | [0x1b, b'O'] => return None,
+                [0x1b, b'[', rest @ ..] => {
+                    // Sequences end with the first byte in the range `@` to `~`
+                    let end = rest.iter().position(|byte| (0x40..=0x7e).contains(byte))? + 2;
+                    let sequence = String::from_utf8_lossy(&self.buffer[2..end]).into_owned();
+                    let last = self.buffer[end];
+                    self.buffer.drain(..=end);
+
+                    if let Some(event) = parse_sequence(&sequence, last) {
+                        return Some(event);
+                    }
+                }
+                [0x1b, b'O', last, ..] => {
+                    let last = *last;
+                    self.buffer.drain(..3);
+                    if let Some(event) = parse_sequence("", last) {
+                        return Some(event);
+                    }
+                }
+                [0x1b, ..] => {
+                    self.buffer.remove(0);
+                    return Some((KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE), None));
+                }
+                bytes => {
+                    // Anything outside of an escape sequence is plain text
+                    let len = match std::str::from_utf8(bytes) {
+                        Ok(_) => bytes.len(),
+                        Err(err) if err.valid_up_to() > 0 => err.valid_up_to(),
+                        Err(err) if err.error_len().is_none() => return None,
+                        Err(_) => {
+                            self.buffer.remove(0);
+                            continue;
+                        }
+                    };
+                    let text = String::from_utf8_lossy(&bytes[..len]).into_owned();
+                    let ch = text.chars().next()?;
+                    self.buffer.drain(..ch.len_utf8());
+                    if !ch.is_control() {
+                        return Some((KeyEvent::new(KeyCode::Char(ch), KeyModifiers::NONE), None));
+                    }
+                }
+            }
+        }
+    }
+}
+
+// Restores the terminal when the app quits, even with an error
+impl Drop for KittyInput {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), PopKeyboardEnhancementFlags);
+    }
+}
+
+// Parses `CSI key:shifted:base ; modifiers:event ; text u` and the legacy
+// sequences the protocol keeps for arrows and function keys
+fn parse_sequence(sequence: &str, last: u8) -> Option<(KeyEvent, Option<PhysicalKey>)> {
+    let mut fields = sequence.split(';');
+    let mut keys = fields.next().unwrap_or_default().split(':');
+    let number = keys.next().and_then(|key| key.parse::<u32>().ok());
+    let shifted_key = keys.next().and_then(parse_codepoint);
+    let base_key = keys.next().and_then(parse_codepoint);
+
+    let mut mods = fields.next().unwrap_or_default().split(':');
+    let mask = mods
+        .next()
+        .and_then(|mask| mask.parse::<u8>().ok())
+        .map_or(0, |mask| mask.saturating_sub(1));
+    let kind = match mods.next() {
+        Some("2") => KeyEventKind::Repeat,
+        Some("3") => KeyEventKind::Release,
+        _ => KeyEventKind::Press,
+    };
+    let text = fields
+        .next()
+        .and_then(|text| text.split(':').next())
+        .and_then(parse_codepoint);
+
+    let mut modifiers = KeyModifiers::NONE;
+    modifiers.set(KeyModifiers::SHIFT, mask & 1 != 0);
+    modifiers.set(KeyModifiers::ALT, mask & 2 != 0);
+    modifiers.set(KeyModifiers::CONTROL, mask & 4 != 0);
+    modifiers.set(KeyModifiers::SUPER, mask & 8 != 0);
+    let mut state = KeyEventState::NONE;
+    state.set(KeyEventState::CAPS_LOCK, mask & 64 != 0);
+
+    let event = |code| KeyEvent::new_with_kind_and_state(code, modifiers, kind, state);
+
+    let code = match (last, number) {
+        (b'u', Some(27)) => KeyCode::Esc,
+        (b'u', Some(13)) => KeyCode::Enter,
+        (b'u', Some(9)) if modifiers.contains(KeyModifiers::SHIFT) => KeyCode::BackTab,
+        (b'u', Some(9)) => KeyCode::Tab,
+        (b'u', Some(8 | 127)) => KeyCode::Backspace,
+        (b'u', Some(number)) => {
+            // Modifiers, the keypad and media keys use the private use area
+            let key = char::from_u32(number).filter(|ch| !ch.is_control())?;
+            if ('\u{e000}'..='\u{f8ff}').contains(&key) {
+                return None;
+            }
+            return Some(char_event(
+                key,
+                shifted_key,
+                base_key,
+                text,
+                modifiers,
+                kind,
+                state,
+            ));
+        }
+        (b'~', Some(2)) => KeyCode::Insert,
+        (b'~', Some(3)) => KeyCode::Delete,
+        (b'~', Some(5)) => KeyCode::PageUp,
+        (b'~', Some(6)) => KeyCode::PageDown,
+        (b'~', Some(7)) => KeyCode::Home,
+        (b'~', Some(8)) => KeyCode::End,
+        (b'~', Some(number @ 11..=15)) => KeyCode::F((number - 10) as u8),
+        (b'~', Some(number @ 17..=21)) => KeyCode::F((number - 11) as u8),
+        (b'~', Some(number @ 23..=24)) => KeyCode::F((number - 12) as u8),
+        (b'A', _) => KeyCode::Up,
+        (b'B', _) => KeyCode::Down,
+        (b'C', _) => KeyCode::Right,
+        (b'D', _) => KeyCode::Left,
+        (b'H', _) => KeyCode::Home,
+        (b'F', _) => KeyCode::End,
+        (b'P', _) => KeyCode::F(1),
+        (b'Q', _) => KeyCode::F(2),
+        (b'S', _) => KeyCode::F(4),
+        _ => return None,
+    };
+    Some((event(code), None))
+}
+
+fn char_event(
+    key: char,
+    shifted_key: Option<char>,
+    base_key: Option<char>,
+    text: Option<char>,
+    mut modifiers: KeyModifiers,
+    kind: KeyEventKind,
+    state: KeyEventState,
+) -> (KeyEvent, Option<PhysicalKey>) {
+    let shifted = modifiers.contains(KeyModifiers::SHIFT);
+    let typed = text.unwrap_or(match shifted_key {
+        Some(shifted_key) if shifted => shifted_key,
+        _ => key,
+    });
+
+    // Text that is not on the plain or shift layer of the key comes from
+    // AltGr, unless the OS combined it with a dead key before
+    let levels = [Some(key), Some(uppercase(key)), shifted_key];
+    let letter = decompose(typed).map_or(typed, |(_, letter)| letter);
+    let altgr = !levels.contains(&Some(typed)) && !levels.contains(&Some(letter));
+
+    // Like crossterm, the typed character already includes shift
+    if typed != key {
+        modifiers.remove(KeyModifiers::SHIFT);
+    }
+    if altgr {
+        modifiers.remove(KeyModifiers::ALT);
+    }
+
+    // The base layout key is left out if it is the key itself
+    let physical = PhysicalKey {
+        base: base_key.unwrap_or(key),
+        shifted,
+        altgr,
+        caps_lock: state.contains(KeyEventState::CAPS_LOCK),
+    };
+    let event = KeyEvent::new_with_kind_and_state(KeyCode::Char(typed), modifiers, kind, state);
+    (event, Some(physical))
+}
+
+fn parse_codepoint(codepoint: &str) -> Option<char> {
+    char::from_u32(codepoint.parse().ok()?)
+}
+
+#[cfg(unix)]
+fn read_stdin(buffer: &mut [u8], timeout: Duration) -> io::Result<usize> {
+    let mut fd = libc::pollfd {
+        fd: libc::STDIN_FILENO,
+        events: libc::POLLIN,
+        revents: 0,
+    };
+    let timeout = timeout.as_millis().min(i32::MAX as u128) as i32;
+
+    // SAFETY: `fd` is a valid pollfd and the count of 1 matches it
+    let ready = unsafe { libc::poll(&mut fd, 1, timeout) };
+    if ready < 0 {
+        let err = io::Error::last_os_error();
+        return match err.kind() {
+            io::ErrorKind::Interrupted => Ok(0),
+            _ => Err(err),
+        };
+    }
+    if ready == 0 {
+        return Ok(0);
+    }
+
+    // SAFETY: `buffer` is valid for writes of `buffer.len()` bytes
+    let len = unsafe { libc::read(libc::STDIN_FILENO, buffer.as_mut_ptr().cast(), buffer.len()) };
+    if len < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(len as usize)
+}
+
+#[cfg(not(unix))]
+fn read_stdin(_buffer: &mut [u8], _timeout: Duration) -> io::Result<usize> {
+    Ok(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(sequence: &str) -> (KeyEvent, PhysicalKey) {
+        let (event, physical) = parse_sequence(sequence, b'u').unwrap();
+        (event, physical.unwrap())
+    }
+
+    #[test]
+    fn parses_plain_key() {
+        let (event, physical) = parse("97;;97");
+        assert_eq!(event, KeyEvent::new(KeyCode::Char('a'), KeyModifiers::NONE));
+        assert_eq!(physical.base, 'a');
+        assert!(!physical.shifted && !physical.altgr && !physical.caps_lock);
+    }
+
+    #[test]
+    fn parses_shifted_key() {
+        // Like crossterm, the typed character already includes shift
+        let (event, physical) = parse("97:65;2;65");
+        assert_eq!(event, KeyEvent::new(KeyCode::Char('A'), KeyModifiers::NONE));
+        assert_eq!(physical.base, 'a');
+        assert!(physical.shifted && !physical.altgr);
+    }
+
+    #[test]
+    fn parses_altgr_key() {
+        let (event, physical) = parse("113;3;64");
+        assert_eq!(event, KeyEvent::new(KeyCode::Char('@'), KeyModifiers::NONE));
+        assert_eq!(physical.base, 'q');
+        assert!(!physical.shifted && physical.altgr);
+    }
+
+    #[test]
+    fn parses_caps_lock() {
+        let (event, physical) = parse("97;65;65");
+        assert_eq!(event.code, KeyCode::Char('A'));
+        assert!(event.state.contains(KeyEventState::CAPS_LOCK));
+        assert!(!physical.shifted && physical.caps_lock);
+    }
+
+    #[test]
+    fn parses_release() {
+        let (event, physical) = parse("97;1:3");
+        assert_eq!(event.code, KeyCode::Char('a'));
+        assert_eq!(event.kind, KeyEventKind::Release);
+        assert_eq!(physical.base, 'a');
+    }
+
+    #[test]
+    fn uses_base_layout_key() {
+        // The key typing y on the OS layout is z on a US layout
+        let (event, physical) = parse("121::122;;121");
+        assert_eq!(event.code, KeyCode::Char('y'));
+        assert_eq!(physical.base, 'z');
+        assert!(!physical.shifted && !physical.altgr);
+    }
+
+    #[test]
+    fn parses_special_keys() {
+        let esc = parse_sequence("27", b'u').unwrap();
+        assert_eq!(esc.0, KeyEvent::new(KeyCode::Esc, KeyModifiers::NONE));
+        assert!(esc.1.is_none());
+
+        let enter = parse_sequence("13;1:3", b'u').unwrap();
+        assert_eq!(enter.0.code, KeyCode::Enter);
+        assert_eq!(enter.0.kind, KeyEventKind::Release);
+        assert!(enter.1.is_none());
+    }
+}
@@ -1,30 +1,35 @@
 use ratatui::widgets::Widget;
 use ratatui::{layout::Size, prelude::*};
 
-use crate::keyboard::AnyKeyboardLayout;
+use crate::state::Summary;
 
-use super::colors::ColorPalette;
-use super::{Centered, Input, Keyboard};
+use super::{Centered, Input, Keyboard, StatsBar};
+
+static WORDS_WIDTH: u16 = 80;
 
 pub struct Main<'a> {
-    word_list: &'a str,
+    word_list: Line<'a>,
+    // The index of the span with the word to type
+    current_word: usize,
     input: Line<'a>,
-    target_layout: AnyKeyboardLayout<'a>,
-    colors: &'a ColorPalette,
+    summary: Summary,
+    keyboard: Keyboard<'a>,
 }
 
 impl<'a> Main<'a> {
     pub fn new(
-        word_list: &'a str,
+        word_list: Line<'a>,
+        current_word: usize,
         input: Line<'a>,
-        target_layout: AnyKeyboardLayout<'a>,
-        colors: &'a ColorPalette,
+        summary: Summary,
+        keyboard: Keyboard<'a>,
     ) -> Self {
         Self {
             word_list,
+            current_word,
             input,
-            target_layout,
-            colors,
+            summary,
+            keyboard,
         }
     }
 }
@@ -34,15 +39,64 @@ impl Widget for Main<'_> {
         let areas = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
+                Constraint::Length(1),
                 Constraint::Percentage(25),
                 Constraint::Percentage(25),
                 Constraint::Percentage(50),
             ])
             .split(area);
 
-        Centered::new(Size::new(80, 5), Input::new(Line::raw(self.word_list)))
-            .render(areas[0], buf);
-        Centered::new(Size::new(40, 5), Input::new(self.input)).render(areas[1], buf);
-        Keyboard::new(self.target_layout, self.colors).render(areas[2], buf);
+        StatsBar::new(self.summary).render(areas[0], buf);
+        // Words that do not fit in the box are left out rather than cut off
+        let words_width = areas[1].width.min(WORDS_WIDTH).saturating_sub(4);
+        let word_list = fit_width(self.word_list, self.current_word, words_width);
+        Centered::new(Size::new(WORDS_WIDTH, 5), Input::new(word_list)).render(areas[1], buf);
+        Centered::new(Size::new(40, 5), Input::new(self.input)).render(areas[2], buf);
+        self.keyboard.render(areas[3], buf);
     }
 }
+
+// Keeps the current span, then as many of the spans after it and right
+// before it as fit in `width` columns
+fn fit_width(line: Line<'_>, current: usize, width: u16) -> Line<'_> {
+    let mut spans = line.spans;
+    let upcoming = spans.split_off((current + 1).min(spans.len()));
+    let current = spans.pop();
+
+    let mut remaining = width as usize;
+    let mut fits = |span: &Span| {
+        let fits = span.width() <= remaining;
+        if fits {
+            remaining -= span.width();
+        }
+        fits
+    };
+    let current = current.filter(&mut fits);
+    let mut upcoming = upcoming
+        .into_iter()
+        .take_while(&mut fits)
+        .collect::<Vec<_>>();
+    let mut history = spans
+        .into_iter()
+        .rev()
+        .take_while(&mut fits)
+        .collect::<Vec<_>>();
+    history.reverse();
+
+    // Spaces next to words that no longer fit
+    let is_space = |span: &Span| span.content.trim().is_empty();
+    if history.first().is_some_and(is_space) {
+        history.remove(0);
+    }
+    if upcoming.last().is_some_and(is_space) {
+        upcoming.pop();
+    }
+
+    Line::from(
+        history
+            .into_iter()
+            .chain(current)
+            .chain(upcoming)
+            .collect::<Vec<_>>(),
+    )
+}
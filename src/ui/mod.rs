@@ -4,7 +4,9 @@ mod colors;
 mod input;
 mod iso_enter;
 mod keyboard;
+mod kitty;
 mod main;
+mod stats_bar;
 
 pub mod screens;
 pub mod widgets;
@@ -13,3 +15,4 @@ pub use app::*;
 pub use centered::*;
 pub use input::*;
 pub use keyboard::*;
+pub use stats_bar::*;
@@ -1,8 +1,15 @@
 use crossterm::event::{KeyCode, KeyEvent};
-use ratatui::widgets::{Block, Padding, Paragraph};
+use ratatui::{
+    style::{Style, Stylize},
+    text::{Line, Span, Text},
+    widgets::{Block, Padding, Paragraph},
+};
 
 use crate::{
-    keyboard::{layouts, AnsiKeyboardLayout, IsoKeyboardLayout},
+    keyboard::{
+        layouts, uppercase, AnsiKeyboardLayout, FormFactor, InputMode, IsoKeyboardLayout,
+        KeyboardLayouts, Level,
+    },
     state::AppState,
     ui::{EventContext, RenderContext, Screen},
 };
@@ -12,21 +19,300 @@ use super::TypingScreen;
 pub struct MenuScreen {
     iso_layouts: Vec<IsoKeyboardLayout>,
     ansi_layouts: Vec<AnsiKeyboardLayout>,
+    focus: MenuField,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum MenuField {
+    FormFactor,
+    InputMode,
+    Source,
+    Target,
+    Level,
+    SessionLength,
+    Capitals,
+    ShiftedSymbols,
+    Adaptive,
 }
 
 impl MenuScreen {
     pub fn new() -> Self {
         Self {
-            iso_layouts: vec![
-                layouts::qwerty::iso(),
-                layouts::qwertz::iso(),
-                layouts::colemak_dh::iso(),
-            ],
-            ansi_layouts: vec![
-                layouts::qwerty::ansi(),
-                layouts::qwertz::ansi(),
-                layouts::colemak_dh::ansi(),
-            ],
+            iso_layouts: layouts::all_iso(),
+            ansi_layouts: layouts::all_ansi(),
+            focus: MenuField::Level,
+        }
+    }
+
+    fn text(&self, state: &AppState) -> Text<'static> {
+        let form_factor = match state.layouts.form_factor() {
+            FormFactor::Iso => "ISO",
+            FormFactor::Ansi => "ANSI",
+        };
+
+        let input_mode = match state.input_mode {
+            InputMode::Remap => "Remap from OS layout",
+            InputMode::Passthrough => "OS already remapped",
+        };
+
+        let mut lines = vec![
+            Line::raw("Welcome to the colemak trainer. Please press <Enter> to start."),
+            Line::raw(""),
+            Line::raw("Select a setting with <Up> and <Down>, change it with <Left> and <Right>:")
+                .bold(),
+            Line::raw(""),
+            self.field_line(MenuField::FormFactor, "Keyboard", form_factor),
+            self.field_line(MenuField::InputMode, "Input", input_mode),
+            // The OS layout is not needed if it already is the layout to learn
+            if state.input_mode == InputMode::Passthrough {
+                self.field_line(
+                    MenuField::Source,
+                    "Your OS layout",
+                    state.layouts.target_name(),
+                )
+                .dim()
+            } else {
+                self.field_line(
+                    MenuField::Source,
+                    "Your OS layout",
+                    state.layouts.source_name(),
+                )
+            },
+            self.field_line(
+                MenuField::Target,
+                "Layout to learn",
+                state.layouts.target_name(),
+            ),
+            self.field_line(MenuField::Level, "Level", &state.level.number().to_string()),
+            self.field_line(
+                MenuField::SessionLength,
+                "Session length",
+                &state.session_length.to_string(),
+            ),
+            self.field_line(
+                MenuField::Capitals,
+                "Capital letters",
+                Self::on_off(state.capitals),
+            ),
+            // Punctuation and its shift layer only unlock at level seven
+            if state.level < Level::Seven {
+                self.field_line(
+                    MenuField::ShiftedSymbols,
+                    "Shifted symbols",
+                    &format!("{} from level 7", Self::on_off(state.shifted_symbols)),
+                )
+                .dim()
+            } else {
+                self.field_line(
+                    MenuField::ShiftedSymbols,
+                    "Shifted symbols",
+                    Self::on_off(state.shifted_symbols),
+                )
+            },
+            self.field_line(
+                MenuField::Adaptive,
+                "Focus weak keys",
+                Self::on_off(state.adaptive),
+            ),
+            Line::raw(""),
+        ];
+
+        for level in Level::ALL {
+            let selected = level == state.level;
+            let completed = state.is_completed(level);
+            let letters = state
+                .layouts
+                .unlocked_target_letters(level)
+                .into_iter()
+                .map(|ch| uppercase(ch).to_string())
+                .collect::<Vec<_>>()
+                .join(" ");
+
+            let line = Line::raw(format!(
+                "{} Level {}  {}  {}",
+                if selected { ">" } else { " " },
+                level.number(),
+                if completed { "✓" } else { " " },
+                letters
+            ));
+            lines.push(if selected {
+                line.style(Style::new().bold())
+            } else if completed {
+                line.style(Style::new().green())
+            } else {
+                line
+            });
+        }
+
+        if !state.warnings.is_empty() {
+            lines.push(Line::raw(""));
+            lines.push(
+                Line::raw("Some layout files could not be loaded:")
+                    .red()
+                    .bold(),
+            );
+            for warning in &state.warnings {
+                lines.push(Line::raw(format!("  {}", warning)).red());
+            }
+        }
+
+        lines.extend([
+            Line::raw(""),
+            Line::raw("While typing, press <Tab> to toggle finger hints and <F2> to show"),
+            Line::raw("the characters on the AltGr layer."),
+            Line::raw(""),
+            Line::raw("Press <Esc> or <Q> to quit."),
+        ]);
+
+        lines.into()
+    }
+
+    fn field_line(&self, field: MenuField, label: &str, value: &str) -> Line<'static> {
+        let focused = field == self.focus;
+        let value = Span::raw(format!("< {} >", value));
+
+        Line::from(vec![
+            Span::raw(format!("{} {:<17}", if focused { ">" } else { " " }, label)),
+            if focused {
+                value.bold().reversed()
+            } else {
+                value
+            },
+        ])
+    }
+
+    fn on_off(enabled: bool) -> &'static str {
+        if enabled {
+            "On"
+        } else {
+            "Off"
+        }
+    }
+
+    fn move_focus(&mut self, forward: bool) {
+        const FIELDS: [MenuField; 9] = [
+            MenuField::FormFactor,
+            MenuField::InputMode,
+            MenuField::Source,
+            MenuField::Target,
+            MenuField::Level,
+            MenuField::SessionLength,
+            MenuField::Capitals,
+            MenuField::ShiftedSymbols,
+            MenuField::Adaptive,
+        ];
+
+        let idx = FIELDS.iter().position(|f| *f == self.focus).unwrap_or(0);
+        let idx = if forward {
+            (idx + 1).min(FIELDS.len() - 1)
+        } else {
+            idx.saturating_sub(1)
+        };
+        self.focus = FIELDS[idx];
+    }
+
+    fn change_value(&self, state: &mut AppState, forward: bool) {
+        let form_factor = state.layouts.form_factor();
+        let source = state.layouts.source_name();
+        let target = state.layouts.target_name();
+
+        let layouts = match self.focus {
+            MenuField::FormFactor => {
+                let form_factor = match form_factor {
+                    FormFactor::Iso => FormFactor::Ansi,
+                    FormFactor::Ansi => FormFactor::Iso,
+                };
+                self.layouts(form_factor, source, target)
+            }
+            MenuField::InputMode => {
+                state.input_mode = match state.input_mode {
+                    InputMode::Remap => InputMode::Passthrough,
+                    InputMode::Passthrough => InputMode::Remap,
+                };
+                None
+            }
+            MenuField::Source => {
+                let source = self.cycle_name(form_factor, source, forward);
+                self.layouts(form_factor, &source, target)
+            }
+            MenuField::Target => {
+                let target = self.cycle_name(form_factor, target, forward);
+                self.layouts(form_factor, source, &target)
+            }
+            MenuField::Level => {
+                let level = if forward {
+                    state.level.next()
+                } else {
+                    state.level.previous()
+                };
+                if let Some(level) = level {
+                    state.level = level;
+                }
+                None
+            }
+            MenuField::SessionLength => {
+                state.session_length = if forward {
+                    state.session_length.next()
+                } else {
+                    state.session_length.previous()
+                };
+                None
+            }
+            MenuField::Capitals => {
+                state.capitals = !state.capitals;
+                None
+            }
+            MenuField::ShiftedSymbols => {
+                state.shifted_symbols = !state.shifted_symbols;
+                None
+            }
+            MenuField::Adaptive => {
+                state.adaptive = !state.adaptive;
+                None
+            }
+        };
+
+        if let Some(layouts) = layouts {
+            state.layouts = layouts;
+        }
+    }
+
+    fn cycle_name(&self, form_factor: FormFactor, current: &str, forward: bool) -> String {
+        let names = match form_factor {
+            FormFactor::Iso => self.iso_layouts.iter().map(|l| &l.name).collect::<Vec<_>>(),
+            FormFactor::Ansi => self.ansi_layouts.iter().map(|l| &l.name).collect(),
+        };
+
+        let idx = names.iter().position(|name| *name == current).unwrap_or(0);
+        let idx = if forward {
+            (idx + 1) % names.len()
+        } else {
+            (idx + names.len() - 1) % names.len()
+        };
+        names[idx].clone()
+    }
+
+    fn layouts(
+        &self,
+        form_factor: FormFactor,
+        source: &str,
+        target: &str,
+    ) -> Option<KeyboardLayouts> {
+        match form_factor {
+            FormFactor::Iso => {
+                let find = |name: &str| self.iso_layouts.iter().find(|l| l.name == name).cloned();
+                Some(KeyboardLayouts::Iso {
+                    source: find(source)?,
+                    target: find(target)?,
+                })
+            }
+            FormFactor::Ansi => {
+                let find = |name: &str| self.ansi_layouts.iter().find(|l| l.name == name).cloned();
+                Some(KeyboardLayouts::Ansi {
+                    source: find(source)?,
+                    target: find(target)?,
+                })
+            }
         }
     }
 }
@@ -36,10 +322,12 @@ impl Screen for MenuScreen {
 
     fn render(&mut self, ctx: &mut RenderContext<'_, '_, '_, Self::AppState>) {
         ctx.render_widget(
-            Paragraph::new("Welcome to the colemak trainer. Please press <Enter> to start.\n\nPress <Esc> or <Q> to quit.")
-            .block(Block::bordered()
-                .title("Welcome")
-                .padding(Padding::uniform(10))))
+            Paragraph::new(self.text(ctx.state)).block(
+                Block::bordered()
+                    .title("Welcome")
+                    .padding(Padding::symmetric(10, 3)),
+            ),
+        )
     }
 
     fn handle_event(&mut self, ctx: &mut EventContext<'_, Self::AppState>, event: KeyEvent) {
@@ -51,8 +339,13 @@ impl Screen for MenuScreen {
             KeyCode::Esc => {
                 ctx.quit();
             }
+            KeyCode::Up => self.move_focus(false),
+            KeyCode::Down => self.move_focus(true),
+            KeyCode::Left => self.change_value(ctx.state, false),
+            KeyCode::Right => self.change_value(ctx.state, true),
             KeyCode::Enter => {
-                let screen = TypingScreen::new(&mut ctx.state);
+                let level = ctx.state.level;
+                let screen = TypingScreen::new(&mut ctx.state, level);
                 ctx.replace_screen(screen);
             }
             KeyCode::Char(ch) => match ch {
@@ -1,5 +1,7 @@
 mod menu;
+mod results;
 mod typing;
 
 pub use menu::*;
+pub use results::*;
 pub use typing::*;
@@ -0,0 +1,219 @@
+use crossterm::event::{KeyCode, KeyEvent};
+use ratatui::{
+    style::Stylize,
+    text::{Line, Span, Text},
+    widgets::{Block, Padding, Paragraph},
+};
+
+use crate::{
+    keyboard::{uppercase, Level},
+    state::{AppState, SessionResult, Summary},
+    ui::{EventContext, RenderContext, Screen},
+};
+
+use super::{MenuScreen, TypingScreen};
+
+static LISTED_KEYS: usize = 5;
+
+pub struct ResultsScreen {
+    result: SessionResult,
+    previous: Option<Summary>,
+    // The level a promotion moved to, or the one after the level just played
+    next_level: Option<Level>,
+    mastery: Line<'static>,
+}
+
+impl ResultsScreen {
+    pub fn new(
+        result: SessionResult,
+        previous: Option<Summary>,
+        promoted: Option<Level>,
+        state: &AppState,
+    ) -> Self {
+        let mastery = match promoted {
+            Some(level) => {
+                let letters = state
+                    .layouts
+                    .unlocked_target_letters(level)
+                    .into_iter()
+                    .map(|ch| uppercase(ch).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                Line::raw(format!(
+                    "Level up! Welcome to level {}, you unlocked {}.",
+                    level.number(),
+                    letters
+                ))
+                .bold()
+                .green()
+            }
+            None if state.is_completed(result.level) => {
+                Line::raw(format!("Level {} is mastered.", result.level.number()))
+            }
+            None => {
+                let progress = state.mastery_progress(result.level);
+                let lagging = progress
+                    .lagging_keys
+                    .iter()
+                    .map(|ch| uppercase(*ch).to_string())
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                Line::raw(format!(
+                    "Mastery: {}/{} words, keys below {:.0} WPM or {:.0}% accuracy: {}",
+                    progress.words,
+                    state.mastery.words,
+                    state.mastery.wpm,
+                    state.mastery.accuracy * 100.0,
+                    if lagging.is_empty() { "none" } else { &lagging }
+                ))
+            }
+        };
+
+        let next_level = promoted.or_else(|| result.level.next());
+
+        Self {
+            result,
+            previous,
+            next_level,
+            mastery,
+        }
+    }
+
+    fn text(&self) -> Text<'static> {
+        let summary = self.result.summary();
+        let secs = summary.elapsed.as_secs();
+
+        let mut lines = vec![
+            Line::from(vec![
+                Span::raw("WPM:       "),
+                Span::raw(format!("{:.0}", summary.net_wpm)).bold(),
+                Span::raw(format!(" (raw {:.0})", summary.raw_wpm)),
+                self.comparison(summary.net_wpm, |previous| previous.net_wpm, ""),
+            ]),
+            Line::from(vec![
+                Span::raw("Accuracy:  "),
+                Span::raw(format!("{:.1}%", summary.accuracy * 100.0)).bold(),
+                self.comparison(
+                    summary.accuracy * 100.0,
+                    |previous| previous.accuracy * 100.0,
+                    "%",
+                ),
+            ]),
+            Line::raw(format!(
+                "Time:      {:02}:{:02} for {} words",
+                secs / 60,
+                secs % 60,
+                self.result.words
+            )),
+            Line::raw(""),
+            self.mastery.clone(),
+            Line::raw(""),
+            Line::raw("Weakest keys:").bold(),
+        ];
+
+        for (ch, stats) in self.result.weakest_keys(LISTED_KEYS) {
+            let latency = stats
+                .mean_latency()
+                .map(|latency| format!("{} ms", latency.as_millis()))
+                .unwrap_or_else(|| String::from("-"));
+            let dwell = stats
+                .mean_dwell()
+                .map(|dwell| format!("held {} ms", dwell.as_millis()))
+                .unwrap_or_default();
+            let rollover = stats
+                .rollover()
+                .map(|rollover| format!("{:.0}% rollover", rollover * 100.0))
+                .unwrap_or_default();
+            lines.push(Line::raw(format!(
+                "  {}   {:>5.1}%   {:>7}   {:>12}   {:>13}",
+                uppercase(ch),
+                stats.accuracy().unwrap_or(1.0) * 100.0,
+                latency,
+                dwell,
+                rollover
+            )));
+        }
+
+        lines.push(Line::raw(""));
+        lines.push(Line::raw("Most common confusions:").bold());
+        let confusions = self.result.confusions(LISTED_KEYS);
+        if confusions.is_empty() {
+            lines.push(Line::raw("  None, well done!"));
+        }
+        for (expected, actual, count) in confusions {
+            lines.push(Line::raw(format!(
+                "  {} typed as {}   {}x",
+                uppercase(expected),
+                uppercase(actual),
+                count
+            )));
+        }
+
+        lines.push(Line::raw(""));
+        let mut options = String::from("Press <R> to retry, ");
+        if self.next_level.is_some() {
+            options.push_str("<N> for the next level, ");
+        }
+        options.push_str("<Esc> or <M> to return to the menu.");
+        lines.push(Line::raw(options));
+
+        lines.into()
+    }
+
+    fn comparison(
+        &self,
+        current: f64,
+        value: impl Fn(&Summary) -> f64,
+        unit: &'static str,
+    ) -> Span<'static> {
+        let Some(previous) = &self.previous else {
+            return Span::raw("");
+        };
+
+        let delta = current - value(previous);
+        let text = format!("   {:+.1}{} vs. last session", delta, unit);
+        if delta >= 0.0 {
+            Span::raw(text).green()
+        } else {
+            Span::raw(text).red()
+        }
+    }
+}
+
+impl Screen for ResultsScreen {
+    type AppState = AppState;
+
+    fn render(&mut self, ctx: &mut RenderContext<'_, '_, '_, Self::AppState>) {
+        ctx.render_widget(
+            Paragraph::new(self.text()).block(
+                Block::bordered()
+                    .title("Session complete")
+                    .padding(Padding::uniform(2)),
+            ),
+        )
+    }
+
+    fn handle_event(&mut self, ctx: &mut EventContext<'_, Self::AppState>, event: KeyEvent) {
+        if !event.modifiers.is_empty() {
+            return;
+        }
+
+        match event.code {
+            KeyCode::Esc | KeyCode::Char('m') => {
+                ctx.replace_screen(MenuScreen::new());
+            }
+            KeyCode::Char('r') => {
+                let screen = TypingScreen::new(ctx.state, self.result.level);
+                ctx.replace_screen(screen);
+            }
+            KeyCode::Char('n') => {
+                if let Some(level) = self.next_level {
+                    ctx.state.level = level;
+                    let screen = TypingScreen::new(ctx.state, level);
+                    ctx.replace_screen(screen);
+                }
+            }
+            _ => {}
+        }
+    }
+}
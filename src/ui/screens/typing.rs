@@ -1,41 +1,199 @@
-use crossterm::event::{KeyCode, KeyEvent};
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+use ratatui::{
+    style::{Style, Stylize},
+    text::{Line, Span},
+};
 
 use crate::{
-    keyboard::LayoutMapper,
-    state::{AppState, WordInput, WordList},
-    ui::{colors::ColorPalette, main::Main, EventContext, RenderContext, Screen},
+    keyboard::{decompose, lowercase, LayoutMapper, Level},
+    state::{
+        AppState, SessionLength, SessionResult, SessionStats, Summary, TypedWord, WordInput,
+        WordList,
+    },
+    ui::{
+        colors::ColorPalette, main::Main, EventContext, KeyFlash, Keyboard, RenderContext, Screen,
+    },
 };
 
-use super::MenuScreen;
+use super::{MenuScreen, ResultsScreen};
+
+static HISTORY: usize = 3;
 
 pub struct TypingScreen {
+    level: Level,
     esc_count: u8,
     word_list: WordList,
+    typed: VecDeque<TypedWord>,
     input: WordInput,
     mapper: LayoutMapper,
+    stats: SessionStats,
+    summary: Summary,
+    flash: Option<KeyFlash>,
+    altgr_legend: bool,
     colors: ColorPalette,
 }
 
 impl TypingScreen {
-    pub fn new(state: &mut AppState) -> Self {
-        let allowed_letters = state.layouts.allowed_target_letters(state.level);
-        let mut word_list = WordList::new(&allowed_letters);
+    pub fn new(state: &mut AppState, level: Level) -> Self {
+        let mut word_list = WordList::new(&state.practice_letters(level), state.difficulty(level));
         let input = WordInput::new(word_list.next_word());
-        let mapper = state.layouts.layout_mapper();
+        let mapper = state.layouts.layout_mapper(state.input_mode);
         let colors = ColorPalette::default();
 
         Self {
+            level,
             esc_count: 0,
             word_list,
+            typed: VecDeque::with_capacity(HISTORY),
             input,
             mapper,
+            stats: SessionStats::new(),
+            summary: Summary::default(),
+            flash: None,
+            altgr_legend: false,
             colors,
         }
     }
 
     fn try_next_word(&mut self) {
         if self.input.is_correct() {
+            self.stats.record_word();
+            if self.typed.len() == HISTORY {
+                self.typed.pop_front();
+            }
+            self.typed.push_back(TypedWord::from(&self.input));
             self.input = WordInput::new(self.word_list.next_word());
+            self.input.set_pending(self.mapper.pending());
+        }
+    }
+
+    // Releases may report another character than the press, e.g. when shift
+    // was let go first, so keys are told apart by their unshifted character
+    fn key_id(ctx: &EventContext<'_, AppState>, c: char) -> char {
+        ctx.physical_key
+            .map(|key| key.base)
+            .unwrap_or_else(|| lowercase(c))
+    }
+
+    // Returns false if the key does not exist on the target layout
+    fn type_chars(&mut self, chars: Vec<char>, now: Instant) -> bool {
+        let pending = self.mapper.pending();
+        self.input.set_pending(pending);
+
+        for &c in &chars {
+            let expected = self.input.next_expected();
+            match expected {
+                Some(expected) => self.stats.record(expected, c, now),
+                None => self.stats.record_other(now),
+            }
+            self.flash = Some(KeyFlash::new(c, expected, now));
+            self.input.push(c);
+        }
+
+        !chars.is_empty() || pending.is_some()
+    }
+
+    // Accented letters are typed with a dead key first, then the letter
+    fn next_key(&self, state: &AppState) -> Option<char> {
+        let expected = self.input.next_expected()?;
+        match decompose(expected) {
+            Some((accent, letter))
+                if state.layouts.target_layout().find(expected).is_none()
+                    && state.layouts.target_layout().dead_keys().contains(&accent) =>
+            {
+                match self.mapper.pending() {
+                    Some(pending) if pending == accent => Some(letter),
+                    _ => Some(accent),
+                }
+            }
+            _ => Some(expected),
+        }
+    }
+
+    fn words_line(&self) -> Line<'static> {
+        let mut spans = Vec::new();
+
+        for typed in &self.typed {
+            let style = if typed.mistyped {
+                Style::new().red().dim()
+            } else {
+                Style::new().dark_gray()
+            };
+            spans.push(Span::styled(typed.word.clone(), style));
+            spans.push(Span::raw(" "));
+        }
+
+        spans.push(Span::styled(
+            self.input.expected().to_string(),
+            Style::new().bold().underlined(),
+        ));
+
+        for word in self.word_list.upcoming() {
+            spans.push(Span::raw(" "));
+            spans.push(Span::raw(word.to_string()));
+        }
+
+        spans.into()
+    }
+
+    fn is_complete(&self, session_length: SessionLength, now: Instant) -> bool {
+        match session_length {
+            SessionLength::Words(words) => self.stats.words() >= words,
+            SessionLength::Seconds(seconds) => {
+                self.stats.elapsed(now) >= Duration::from_secs(seconds.into())
+            }
+        }
+    }
+
+    fn complete_session(&self, ctx: &mut EventContext<'_, AppState>, now: Instant) {
+        let result = SessionResult::new(
+            self.level,
+            ctx.state.layouts.target_name(),
+            &self.stats,
+            self.stats.elapsed(now),
+        );
+        let previous = ctx
+            .state
+            .sessions
+            .iter()
+            .rev()
+            .find(|session| !session.aborted)
+            .map(SessionResult::summary);
+
+        ctx.state.sessions.push(result.clone());
+        let promoted = ctx.state.advance_if_mastered(self.level);
+        Self::save_profile(ctx.state);
+
+        let screen = ResultsScreen::new(result, previous, promoted, ctx.state);
+        ctx.replace_screen(screen);
+    }
+
+    fn abort_session(&self, state: &mut AppState) {
+        if self.stats.is_empty() {
+            return;
+        }
+
+        let result = SessionResult {
+            aborted: true,
+            ..SessionResult::new(
+                self.level,
+                state.layouts.target_name(),
+                &self.stats,
+                self.stats.duration(),
+            )
+        };
+        state.sessions.push(result);
+        Self::save_profile(state);
+    }
+
+    fn save_profile(state: &AppState) {
+        if let Err(err) = state.save() {
+            eprintln!("Could not save profile: {}", err);
         }
     }
 }
@@ -44,42 +202,92 @@ impl Screen for TypingScreen {
     type AppState = AppState;
 
     fn render(&mut self, ctx: &mut RenderContext<'_, '_, '_, Self::AppState>) {
+        let keyboard = Keyboard::new(ctx.state.layouts.target_layout(), &self.colors)
+            .highlight(self.next_key(ctx.state))
+            .finger_hint(ctx.state.finger_hints)
+            .altgr_legend(self.altgr_legend)
+            .flash(self.flash.as_ref(), Instant::now());
+
+        // Each typed word is followed by a space
         ctx.render_widget(Main::new(
-            "Todo todo todo",
+            self.words_line(),
+            self.typed.len() * 2,
             self.input.to_line(),
-            ctx.state.layouts.target_layout(),
-            &self.colors,
+            self.summary,
+            keyboard,
         ));
     }
 
     fn handle_event(&mut self, ctx: &mut EventContext<'_, Self::AppState>, event: KeyEvent) {
-        if !event.modifiers.is_empty() {
+        // Shift is needed for capitals and shifted symbols, and Windows reports
+        // AltGr as Ctrl+Alt
+        let modifiers = event.modifiers.difference(KeyModifiers::SHIFT);
+        if !modifiers.is_empty() && modifiers != KeyModifiers::CONTROL | KeyModifiers::ALT {
             return;
         }
 
+        let now = Instant::now();
+
         match event.code {
             KeyCode::Esc => {
                 self.esc_count += 1;
                 if self.esc_count > 1 {
+                    self.abort_session(ctx.state);
                     ctx.replace_screen(MenuScreen::new());
                 }
             }
 
+            KeyCode::Tab => {
+                self.esc_count = 0;
+                ctx.state.finger_hints = !ctx.state.finger_hints;
+            }
+
+            KeyCode::F(2) => {
+                self.esc_count = 0;
+                self.altgr_legend = !self.altgr_legend;
+            }
+
+            // Space types the accent of a pending dead key on its own
+            KeyCode::Char(' ') if self.mapper.pending().is_some() => {
+                self.esc_count = 0;
+                let chars = self.mapper.map(' ');
+                self.type_chars(chars, now);
+            }
+
             KeyCode::Enter | KeyCode::Char(' ') => {
                 self.esc_count = 0;
-                self.try_next_word()
+                self.stats.record_other(now);
+                // Enter has no release the screen keeps track of
+                if event.code == KeyCode::Char(' ') {
+                    self.stats.record_press(Self::key_id(ctx, ' '), None, now);
+                }
+                self.try_next_word();
+
+                if self.is_complete(ctx.state.session_length, now) {
+                    self.complete_session(ctx, now);
+                }
             }
 
             KeyCode::Backspace => {
                 self.esc_count = 0;
-                self.input.pop();
+                self.stats.record_other(now);
+                if self.mapper.pending().is_some() {
+                    self.mapper.cancel();
+                    self.input.set_pending(None);
+                } else {
+                    self.input.pop();
+                }
             }
 
             KeyCode::Char(c) => {
                 self.esc_count = 0;
-                if let Some(c) = self.mapper.map(c) {
-                    self.input.push(c);
-                } else {
+                let chars = ctx
+                    .physical_key
+                    .and_then(|key| self.mapper.map_physical(key))
+                    .unwrap_or_else(|| self.mapper.map(c));
+                let expected = self.input.next_expected().filter(|_| !chars.is_empty());
+                self.stats.record_press(Self::key_id(ctx, c), expected, now);
+                if !self.type_chars(chars, now) {
                     eprintln!("Could not map key event {:?}", event);
                 }
             }
@@ -87,4 +295,25 @@ impl Screen for TypingScreen {
             _ => {}
         }
     }
+
+    fn handle_release(&mut self, ctx: &mut EventContext<'_, Self::AppState>, event: KeyEvent) {
+        if let KeyCode::Char(c) = event.code {
+            self.stats
+                .record_release(Self::key_id(ctx, c), Instant::now());
+        }
+    }
+
+    fn tick(&mut self, ctx: &mut EventContext<'_, Self::AppState>) {
+        let now = Instant::now();
+        let elapsed = self.stats.elapsed(now);
+        self.summary = self.stats.summary(elapsed);
+
+        if self.flash.as_ref().is_some_and(|flash| flash.is_over(now)) {
+            self.flash = None;
+        }
+
+        if !self.stats.is_empty() && self.is_complete(ctx.state.session_length, now) {
+            self.complete_session(ctx, now);
+        }
+    }
 }
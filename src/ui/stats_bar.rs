@@ -0,0 +1,31 @@
+use ratatui::prelude::*;
+
+use crate::state::Summary;
+
+pub struct StatsBar {
+    summary: Summary,
+}
+
+impl StatsBar {
+    pub fn new(summary: Summary) -> Self {
+        Self { summary }
+    }
+}
+
+impl Widget for StatsBar {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let secs = self.summary.elapsed.as_secs();
+        let spans = vec![
+            Span::raw("WPM "),
+            Span::raw(format!("{:.0}", self.summary.net_wpm)).bold(),
+            Span::raw("   Raw "),
+            Span::raw(format!("{:.0}", self.summary.raw_wpm)).bold(),
+            Span::raw("   Accuracy "),
+            Span::raw(format!("{:.1}%", self.summary.accuracy * 100.0)).bold(),
+            Span::raw("   Time "),
+            Span::raw(format!("{:02}:{:02}", secs / 60, secs % 60)).bold(),
+        ];
+
+        Line::from(spans).centered().render(area, buf);
+    }
+}